@@ -0,0 +1,205 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_view::{ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::{AdditiveIdentity, Scalar};
+use std::alloc::{AllocError, Allocator, Global, Layout};
+use std::ptr::NonNull;
+use std::simd::{Simd, SimdElement};
+
+// ### Storage of the Buffer of `Array<T, D, A>`
+/// Allocators that may hold the buffer of an `Array`: `Global` (the default) and `AlignedAlloc<ALIGN>`
+///
+/// `ALIGN` is the alignment in bytes guaranteed for the start of every buffer, on top of the alignment of the element type itself.
+pub trait StorageAllocator: Allocator + Clone {
+    const ALIGN: usize;
+    /// Collect elements into a buffer of this allocator
+    ///
+    /// For `Global` this is a plain `collect`, so that a `Vec<T>` or an in-place `map` over one keeps its buffer.
+    fn collect_in<T, I: IntoIterator<Item = T>>(&self, iter: I) -> Vec<T, Self>;
+}
+impl StorageAllocator for Global {
+    const ALIGN: usize = 1;
+    fn collect_in<T, I: IntoIterator<Item = T>>(&self, iter: I) -> Vec<T, Self> {
+        iter.into_iter().collect()
+    }
+}
+
+/// Allocator whose buffers start at a multiple of `ALIGN` bytes (a power of two, e.g., 32 for AVX2 or 64 for AVX-512 and cache lines)
+///
+/// It forwards to `Global` with the alignment of every layout raised to `ALIGN`, both on allocation and on deallocation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlignedAlloc<const ALIGN: usize>;
+impl<const ALIGN: usize> AlignedAlloc<ALIGN> {
+    const POWER_OF_TWO: () = assert!(
+        ALIGN.is_power_of_two(),
+        "Check Input: the alignment must be a power of two!"
+    );
+    fn aligned(layout: Layout) -> Result<Layout, AllocError> {
+        let () = Self::POWER_OF_TWO;
+        layout.align_to(ALIGN).map_err(|_| AllocError)
+    }
+}
+unsafe impl<const ALIGN: usize> Allocator for AlignedAlloc<ALIGN> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        Global.allocate(Self::aligned(layout)?)
+    }
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        // the layout was raised in exactly the same way when `ptr` was allocated
+        Global.deallocate(ptr, Self::aligned(layout).unwrap_unchecked())
+    }
+}
+impl<const ALIGN: usize> StorageAllocator for AlignedAlloc<ALIGN> {
+    const ALIGN: usize = ALIGN;
+    fn collect_in<T, I: IntoIterator<Item = T>>(&self, iter: I) -> Vec<T, Self> {
+        let iter = iter.into_iter();
+        let mut data = Vec::with_capacity_in(iter.size_hint().0, *self);
+        data.extend(iter);
+        data
+    }
+}
+
+/// Array whose buffer starts at a multiple of `ALIGN` bytes, e.g., `AlignedArray<f64, 2, 32>`
+pub type AlignedArray<T, const D: usize, const ALIGN: usize> = Array<T, D, AlignedAlloc<ALIGN>>;
+
+impl<T: Scalar, const D: usize, A: StorageAllocator> Array<T, D, A> {
+    /// Alignment in bytes guaranteed for the start of `data`
+    pub fn alignment(&self) -> usize {
+        A::ALIGN.max(std::mem::align_of::<T>())
+    }
+    /// Move the elements into a buffer of `allocator`; moving into `Global` from `Global` keeps the buffer
+    pub fn into_allocator<B: StorageAllocator>(self, allocator: B) -> Array<T, D, B> {
+        Array {
+            data: allocator.collect_in(self.data),
+            shape: self.shape,
+            data_order: self.data_order,
+        }
+    }
+    /// Move the elements into a buffer aligned to `ALIGN` bytes
+    pub fn into_aligned<const ALIGN: usize>(self) -> AlignedArray<T, D, ALIGN> {
+        self.into_allocator(AlignedAlloc::<ALIGN>)
+    }
+}
+
+impl<T: Scalar + SimdElement, const D: usize, const ALIGN: usize> AlignedArray<T, D, ALIGN> {
+    /// Split `data` into SIMD vectors of `LANES` elements, read with aligned loads, and the remaining scalars
+    ///
+    /// The alignment of the buffer must cover a whole vector, which is checked at compile time, e.g., `ALIGN >= 32` for `f64x4`.
+    pub fn as_simd_chunks<const LANES: usize>(&self) -> (&[Simd<T, LANES>], &[T]) {
+        const {
+            assert!(
+                ALIGN >= std::mem::align_of::<Simd<T, LANES>>(),
+                "Check Input: the buffer alignment does not cover a SIMD vector!"
+            )
+        };
+        let chunks = self.data.len() / LANES;
+        if chunks == 0 {
+            // the dangling pointer of an empty buffer is only aligned to `T`, so it may not be cast
+            return (&[], &self.data[..]);
+        }
+        let (head, tail) = self.data.split_at(chunks * LANES);
+        // `head` starts at the aligned start of the buffer and holds whole vectors of `LANES` elements without padding
+        let head = unsafe { std::slice::from_raw_parts(head.as_ptr().cast(), chunks) };
+        (head, tail)
+    }
+}
+
+// ### Aligned Storage with a Padded Leading Dimension
+/// Aligned array whose fastest axis (the first for column-major and the last for row-major data) is padded to a multiple of the SIMD lane count `ALIGN / size_of::<T>()`
+///
+/// Every line along that axis then starts at a multiple of `ALIGN` bytes and spans whole SIMD vectors, so kernels can load it with aligned loads and no remainder loop. The padding is kept at zero; `view()` and `view_mut()` cover the logical `shape` only.
+#[derive(Debug, Clone)]
+pub struct PaddedArray<T: Scalar, const D: usize, const ALIGN: usize> {
+    pub storage: AlignedArray<T, D, ALIGN>,
+    pub shape: [usize; D],
+}
+
+impl<T: Scalar, const D: usize, const ALIGN: usize> PaddedArray<T, D, ALIGN> {
+    /// Number of elements of `T` in one SIMD vector of `ALIGN` bytes
+    pub const LANES: usize = {
+        assert!(
+            ALIGN.is_multiple_of(std::mem::size_of::<T>()),
+            "Check Input: the alignment must hold whole elements!"
+        );
+        ALIGN / std::mem::size_of::<T>()
+    };
+    /// Distance in elements between consecutive lines along the fastest axis, a multiple of `LANES`
+    pub fn leading_dimension(&self) -> usize {
+        match leading_axis::<D>(self.storage.data_order) {
+            Some(axis) => self.storage.shape[axis],
+            None => 1,
+        }
+    }
+    /// Read-only view of the logical elements, skipping the padding
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView {
+            shape: self.shape,
+            ..self.storage.view()
+        }
+    }
+    /// Mutable view of the logical elements, skipping the padding
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        let shape = self.shape;
+        ArrayViewMut {
+            shape,
+            ..self.storage.view_mut()
+        }
+    }
+}
+
+impl<T: AdditiveIdentity, const D: usize, const ALIGN: usize> PaddedArray<T, D, ALIGN> {
+    /// Padded array of the given logical shape with every element zero
+    pub fn zeros(shape: [usize; D], data_order: DataOrder) -> Self {
+        let mut padded_shape = shape;
+        if let Some(axis) = leading_axis::<D>(data_order) {
+            padded_shape[axis] = shape[axis].div_ceil(Self::LANES) * Self::LANES;
+        }
+        PaddedArray {
+            storage: Array {
+                data: AlignedAlloc::<ALIGN>
+                    .collect_in(std::iter::repeat_n(T::ZERO, padded_shape.iter().product())),
+                shape: padded_shape,
+                data_order,
+            },
+            shape,
+        }
+    }
+    /// Copy the elements of an array or a view into padded storage
+    pub fn from_view<V: AsArrayView<T, D>>(src: &V, data_order: DataOrder) -> Self {
+        let src = src.as_view();
+        let mut padded = PaddedArray::zeros(src.shape, data_order);
+        padded.view_mut().assign(&src);
+        padded
+    }
+}
+
+/// The axis that runs fastest in memory, if the array has any
+fn leading_axis<const D: usize>(data_order: DataOrder) -> Option<usize> {
+    match data_order {
+        _ if D == 0 => None,
+        DataOrder::ColMajor => Some(0),
+        DataOrder::RowMajor => Some(D - 1),
+    }
+}
+
+impl<T: Scalar + SimdElement, const D: usize, const ALIGN: usize> PaddedArray<T, D, ALIGN> {
+    /// Iterate the padded lines along the fastest axis (e.g., the columns of a column-major matrix) as SIMD vectors read with aligned loads, see `AlignedArray::as_simd_chunks`
+    ///
+    /// Each line holds `leading_dimension() / LANES` vectors, whose padding lanes are zero. `LANES` must divide `Self::LANES`, which is checked at compile time, so that no line ends in a partial vector. A zero-dimensional array has no line, so nothing is yielded.
+    pub fn simd_lines<const LANES: usize>(&self) -> impl Iterator<Item = &[Simd<T, LANES>]> + '_ {
+        const {
+            assert!(
+                Self::LANES.is_multiple_of(LANES),
+                "Check Input: the SIMD lanes do not divide the padding of the lines!"
+            )
+        };
+        // the single element of a zero-dimensional array is not padded into a line
+        let (chunks, tail) = match D {
+            0 => (&[][..], &[][..]),
+            _ => self.storage.as_simd_chunks::<LANES>(),
+        };
+        assert!(
+            tail.is_empty(),
+            "Check Input: the elements do not fill whole SIMD vectors!"
+        );
+        chunks.chunks_exact((self.leading_dimension() / LANES).max(1))
+    }
+}
@@ -1,8 +1,12 @@
+use crate::array_aligned::StorageAllocator;
+use crate::array_error::{check_axis, check_same_size, ShapeError};
+use crate::array_view::{strided_data_index, ColMajorIndices};
 use crate::scalar::Scalar;
 // use num_traits::*;
-use std::ops::{Add, Mul, Sub};
+use std::alloc::{Allocator, Global};
+use std::cmp::Ordering;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DataOrder {
     RowMajor,
     ColMajor,
@@ -14,58 +18,80 @@ impl DataOrder {
             DataOrder::ColMajor => DataOrder::RowMajor,
         }
     }
+    /// Per-axis strides of a packed buffer of the given shape stored in this order
+    ///
+    /// Column-major strides are `[1, n_0, n_0 * n_1, ...]` and row-major strides are `[..., n_{D-1} * n_{D-2}, n_{D-1}, 1]`.
+    pub fn strides<const D: usize>(&self, shape: &[usize; D]) -> [isize; D] {
+        let mut strides = [0isize; D];
+        self.fill_strides(shape, &mut strides);
+        strides
+    }
+    /// Per-axis strides for a shape whose dimension is only known at runtime
+    pub fn dyn_strides(&self, shape: &[usize]) -> Vec<isize> {
+        let mut strides = vec![0isize; shape.len()];
+        self.fill_strides(shape, &mut strides);
+        strides
+    }
+    fn fill_strides(&self, shape: &[usize], strides: &mut [isize]) {
+        let mut product = 1;
+        match self {
+            DataOrder::ColMajor => {
+                for axis in 0..shape.len() {
+                    strides[axis] = product;
+                    product *= shape[axis] as isize;
+                }
+            }
+            DataOrder::RowMajor => {
+                for axis in (0..shape.len()).rev() {
+                    strides[axis] = product;
+                    product *= shape[axis] as isize;
+                }
+            }
+        }
+    }
 }
 
 // ### General Array Container of Arbitrary Dimension
-/// of generic scalar datatype `data: Vec<T: Scalar>` and dimensions `shape: Vec<usize>`.
+/// of generic scalar datatype `data: Vec<T: Scalar>` and dimensions `shape: [usize; D]`.
 ///
-/// We use `Scalar` type here for generic usage, including float, string, and symbolic variables. And we use `Vec<usize>` for the shape rather than `[usize; N]` with compile-time const generics `N` simply because we want to support dynamic dimensionality, such as fast `reshape` method.
+/// We use `Scalar` type here for generic usage, including float, string, and symbolic variables. The dimension `D` is a compile-time const generic, so that indices and slices are checked by the type system; see `ArrayD` for arrays whose dimensionality is only known at runtime.
 ///
 /// The Array is set to be **column-major** by default, meaning that the data of array-position `[i,j,k,...]` is stored at `data_index = i + j * i_length + k * j_length * i_length + ...`. This is the same as the convention of `numpy`, `Fortran`, and `julia`.
+///
+/// The buffer lives in the allocator `A`, which is `Global` unless the array is explicitly aligned, see `AlignedArray`.
 #[derive(Debug, Clone)]
-pub struct Array<T: Scalar, const D: usize> {
-    pub data: Vec<T>,
+pub struct Array<T: Scalar, const D: usize, A: Allocator = Global> {
+    pub data: Vec<T, A>,
     pub shape: [usize; D],
     pub data_order: DataOrder,
 }
 
 /* Method implementations for general dimensions */
 impl<T: Scalar, const D: usize> Array<T, D> {
-    /// Reshape by clone
-    pub fn reshape(&self, new_shape: [usize; D]) -> Self {
-        assert!(new_shape.iter().product::<usize>() == self.data.len());
-        Array {
-            data: self.data.clone(),
-            shape: new_shape,
-            data_order: self.data_order,
-        }
+    /// Reshape by clone, possibly into a different dimension, e.g., `m.reshape([2, 1, 3])` for `m` of shape `[2, 3]`
+    ///
+    /// The data is reinterpreted in its own `data_order`.
+    pub fn reshape<const E: usize>(&self, new_shape: [usize; E]) -> Array<T, E> {
+        self.try_reshape(new_shape)
+            .unwrap_or_else(|error| panic!("{error}"))
     }
-    /// Reshape in-place
-    pub fn reshape_inplace(self, new_shape: [usize; D]) -> Self {
-        assert!(new_shape.iter().product::<usize>() == self.data.len());
-        Array {
-            data: self.data,
+    /// Fallible `reshape`, returning `ShapeError::SizeMismatch` if the number of elements differs
+    pub fn try_reshape<const E: usize>(
+        &self,
+        new_shape: [usize; E],
+    ) -> Result<Array<T, E>, ShapeError> {
+        check_same_size(&self.shape, &new_shape)?;
+        Ok(Array {
+            data: self.data.clone(),
             shape: new_shape,
             data_order: self.data_order,
-        }
+        })
     }
     /// Element-wisely Broadcast with closures by clone
     ///
     /// Here we use generics to allow type conversion for data. Example usage: `m.map(|x| x as f64)` for `m` of `Array<i32>`
     pub fn map<U: Scalar, F: Fn(T) -> U>(&self, func: F) -> Array<U, D> {
-        Array {
-            data: self.data.clone().into_iter().map(func).collect::<Vec<U>>(),
-            shape: self.shape,
-            data_order: self.data_order,
-        }
-    }
-    /// Element-wisely Broadcast with closures in-place
-    pub fn map_inplace<U: Scalar, F: Fn(T) -> U>(self, func: F) -> Array<U, D> {
-        Array {
-            data: self.data.into_iter().map(func).collect::<Vec<U>>(),
-            shape: self.shape,
-            data_order: self.data_order,
-        }
+        self.view().map(func)
     }
     /// Get the maximum string length for the element: also work for future symbolic variables!
     pub fn get_element_length_and_interval(
@@ -73,172 +99,202 @@ impl<T: Scalar, const D: usize> Array<T, D> {
         decimal_length: usize,
         element_interval: usize,
     ) -> usize {
-        let width = self
-            .data
-            .iter()
-            .map(|x| format!("{:>.decimal_length$}", x, decimal_length = decimal_length).len())
-            .max()
-            .unwrap();
-
-        width + element_interval
+        self.view()
+            .get_element_length_and_interval(decimal_length, element_interval)
     }
 }
 
-/* Trait implementations for general dimensions */
-/// array addition by clone, namely `&A+&B` create a new array: both `A` and `B` are not consumed
-impl<T: Scalar + Add<Output = T>, const D: usize> Add for &Array<T, D> {
-    type Output = Array<T, D>;
-    fn add(self, rhs: Self) -> Self::Output {
-        assert!(
-            self.shape.iter().eq(rhs.shape.iter()),
-            "Check Input: Dimension Mismatch!"
-        );
-        let res_data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| a.clone() + b.clone())
-            .collect();
+/* Consuming methods, which keep the allocator of the buffer */
+impl<T: Scalar, const D: usize, A: StorageAllocator> Array<T, D, A> {
+    /// Reshape in-place, possibly into a different dimension
+    pub fn reshape_inplace<const E: usize>(self, new_shape: [usize; E]) -> Array<T, E, A> {
+        self.try_reshape_inplace(new_shape)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `reshape_inplace`; the array is dropped on failure
+    pub fn try_reshape_inplace<const E: usize>(
+        self,
+        new_shape: [usize; E],
+    ) -> Result<Array<T, E, A>, ShapeError> {
+        check_same_size(&self.shape, &new_shape)?;
+        Ok(Array {
+            data: self.data,
+            shape: new_shape,
+            data_order: self.data_order,
+        })
+    }
+    /// Element-wisely Broadcast with closures in-place
+    pub fn map_inplace<U: Scalar, F: Fn(T) -> U>(self, func: F) -> Array<U, D, A> {
+        let allocator = self.data.allocator().clone();
         Array {
-            data: res_data,
+            data: allocator.collect_in(self.data.into_iter().map(func)),
             shape: self.shape,
             data_order: self.data_order,
         }
     }
 }
-/// array addition in-place, namely `A+B` consume both `A` and `B` and the resultant is stored in `A`
-impl<T: Scalar + Add<Output = T>, const D: usize> Add for Array<T, D> {
-    type Output = Self;
-    fn add(mut self, rhs: Self) -> Self::Output {
-        assert!(self.shape == rhs.shape, "Dimension Mismatch!");
-        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
-            *a = a.clone() + b.clone();
-        }
-        self
-    }
+
+/* Searching and sorting */
+/// Total order for sorting which puts every `NaN` (anything unordered with itself) last, as julia's `isless`
+pub fn nan_last_cmp<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    a.partial_cmp(b).unwrap_or_else(|| {
+        let (a_is_nan, b_is_nan) = (a.partial_cmp(a).is_none(), b.partial_cmp(b).is_none());
+        a_is_nan.cmp(&b_is_nan)
+    })
 }
-/// array substraction by clone, namely `&A-&B` create a new array: both `A` and `B` are not consumed
-impl<T: Scalar + Sub<Output = T>, const D: usize> Sub for &Array<T, D> {
-    type Output = Array<T, D>;
-    fn sub(self, rhs: Self) -> Self::Output {
-        assert!(
-            self.shape.iter().eq(rhs.shape.iter()),
-            "Check Input: Dimension Mismatch!"
-        );
-        let res_data = self
-            .data
-            .iter()
-            .zip(rhs.data.iter())
-            .map(|(a, b)| a.clone() - b.clone())
-            .collect();
-        Array {
-            data: res_data,
-            shape: self.shape,
-            data_order: self.data_order,
+/// Position of the first extreme element according to `better`, where a `NaN` wins as in julia's `findmax` / `findmin`
+fn position_of_extreme<'a, T: PartialOrd + 'a, F: Fn(&T, &T) -> bool>(
+    values: impl Iterator<Item = &'a T>,
+    better: F,
+) -> Option<usize> {
+    let mut best: Option<(usize, &T)> = None;
+    for (position, x) in values.enumerate() {
+        match best {
+            Some((_, y)) if y.partial_cmp(y).is_none() => break, // a NaN has been found
+            Some((_, y)) if !(better(x, y) || x.partial_cmp(x).is_none()) => {}
+            _ => best = Some((position, x)),
         }
     }
+    best.map(|(position, _)| position)
 }
-/// array subtraction in-place, namely `A+B` consume both `A` and `B` and the resultant is stored in `A`
-impl<T: Scalar + Sub<Output = T>, const D: usize> Sub for Array<T, D> {
-    type Output = Self;
-    fn sub(mut self, rhs: Self) -> Self::Output {
-        assert!(self.shape == rhs.shape, "Dimension Mismatch!");
-        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
-            *a = a.clone() - b.clone();
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Multi-index `[i,j,k,...]` of the first largest element in column-major order (or of the first `NaN`), `None` for an empty array
+    pub fn argmax(&self) -> Option<[usize; D]>
+    where
+        T: PartialOrd,
+    {
+        let position = position_of_extreme(self.iter(), |x, y| x > y)?;
+        ColMajorIndices::new(self.shape).nth(position)
+    }
+    /// Multi-index `[i,j,k,...]` of the first smallest element in column-major order (or of the first `NaN`), `None` for an empty array
+    pub fn argmin(&self) -> Option<[usize; D]>
+    where
+        T: PartialOrd,
+    {
+        let position = position_of_extreme(self.iter(), |x, y| x < y)?;
+        ColMajorIndices::new(self.shape).nth(position)
+    }
+    /// Index along `axis` of the largest element of every lane, keeping `axis` with extent one, as julia's `argmax(A; dims)`
+    pub fn argmax_axis(&self, axis: usize) -> Array<usize, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, Some(1), |lane| {
+            let position = position_of_extreme(lane.iter(), |x, y| x > y);
+            vec![position.expect("Check Input: cannot reduce over an axis of extent zero!")]
+        })
+    }
+    /// Index along `axis` of the smallest element of every lane, keeping `axis` with extent one, as julia's `argmin(A; dims)`
+    pub fn argmin_axis(&self, axis: usize) -> Array<usize, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, Some(1), |lane| {
+            let position = position_of_extreme(lane.iter(), |x, y| x < y);
+            vec![position.expect("Check Input: cannot reduce over an axis of extent zero!")]
+        })
+    }
+    /// Multi-indices of all elements satisfying `predicate`, in column-major order, as julia's `findall(predicate, A)`
+    pub fn findall<F: Fn(&T) -> bool>(&self, predicate: F) -> Vec<[usize; D]> {
+        self.indexed_iter()
+            .filter(|(_, x)| predicate(x))
+            .map(|(indices, _)| indices)
+            .collect()
+    }
+    /// Sort every lane along `axis` in ascending order with `NaN` last, as julia's `sort(A; dims)`
+    ///
+    /// Equal elements may be reordered, which only matters for `sortperm`; see `sort_stable`.
+    pub fn sort(&self, axis: usize) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, None, |mut lane| {
+            lane.sort_unstable_by(nan_last_cmp);
+            lane
+        })
+    }
+    /// Sort every lane along `axis` in ascending order with `NaN` last, keeping equal elements in their order
+    pub fn sort_stable(&self, axis: usize) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, None, |mut lane| {
+            lane.sort_by(nan_last_cmp);
+            lane
+        })
+    }
+    /// Indices along `axis` that sort every lane, as julia's `sortperm`, i.e., `sort(axis)` gathers the elements at these indices
+    ///
+    /// Equal elements may appear in any order; see `sortperm_stable`.
+    pub fn sortperm(&self, axis: usize) -> Array<usize, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, None, |lane| {
+            let mut perm: Vec<usize> = (0..lane.len()).collect();
+            perm.sort_unstable_by(|&i, &j| nan_last_cmp(&lane[i], &lane[j]));
+            perm
+        })
+    }
+    /// Indices along `axis` that sort every lane, with equal elements in increasing index order
+    pub fn sortperm_stable(&self, axis: usize) -> Array<usize, D>
+    where
+        T: PartialOrd,
+    {
+        self.map_lanes(axis, None, |lane| {
+            let mut perm: Vec<usize> = (0..lane.len()).collect();
+            perm.sort_by(|&i, &j| nan_last_cmp(&lane[i], &lane[j]));
+            perm
+        })
+    }
+    /// Replace every lane along `axis` by `func` of its elements, which must return `extent` values, or as many as it got for `None`; the result is column-major
+    ///
+    /// Panics with `ShapeError::AxisOutOfBounds` if `axis` is not less than `D`.
+    fn map_lanes<U: Scalar, F: Fn(Vec<T>) -> Vec<U>>(
+        &self,
+        axis: usize,
+        extent: Option<usize>,
+        func: F,
+    ) -> Array<U, D> {
+        check_axis(axis, D).unwrap_or_else(|error| panic!("{error}"));
+        let mut shape = self.shape;
+        shape[axis] = extent.unwrap_or(self.shape[axis]);
+        let strides = DataOrder::ColMajor.strides(&shape);
+        let mut data: Vec<Option<U>> = (0..shape.iter().product()).map(|_| None).collect();
+        let mut outer_shape = shape;
+        outer_shape[axis] = 1;
+        for (base, lane) in ColMajorIndices::new(outer_shape).zip(self.lanes(axis)) {
+            let base = strided_data_index(0, &strides, &base) as isize;
+            for (i, x) in func(lane.iter().cloned().collect()).into_iter().enumerate() {
+                data[(base + i as isize * strides[axis]) as usize] = Some(x);
+            }
+        }
+        Array {
+            data: data
+                .into_iter()
+                .map(|x| x.expect("every lane returns `extent` values"))
+                .collect(),
+            shape,
+            data_order: DataOrder::ColMajor,
         }
-        self
     }
 }
 
 /* Macros */
 /// Calculate the `data_index` of the one-dimensional raw data of `Array` for the given multi-dimensional indices `[i,j,k,...]`.
 ///
-/// The **column major** relation is `data_index = i + j * i_length + k * j_length * i_length + ...`
+/// The **column major** relation is `data_index = i + j * i_length + k * j_length * i_length + ...`, and the **row major** relation runs the other way round, i.e., the last index is the fastest. Out-of-bound indices panic with the offending axis.
 #[macro_export]
 macro_rules! calculate_data_index {
     ($array:expr, $indices:expr) => {{
-        assert!($indices.len() == $array.shape.len()); // ensure the number of indices is equal to the dimension of array
-        assert!($indices.len() > 0);
-
-        let mut data_index = 0;
-        let mut product = 1;
-        match $indices.len() {
-            1 => {
-                data_index = $indices[0];
-            }
-            2 => {
-                data_index = $indices[0] + $indices[1] * $array.shape[0];
-            }
-            _ => {
-                for (current_dim, &current_index) in $indices.iter().enumerate() {
-                    data_index += current_index * product;
-                    product *= $array.shape[current_dim];
-                }
-                assert!(data_index < $array.data.len()); // ensure the index is within the range of data
-            }
-        }
-        data_index
+        $array.data_index(&$indices)
     }};
 }
 
-/// Initialize multi-dimensional array with every element zero or one
-///
-/// Example usage: `zeros!(f64,2,3,4)`
-#[macro_export]
-macro_rules! zeros {
-    ($type:ty, $($dim:expr),+) => {
-        {
-            use crate::scalar::Scalar;
-            let shape = vec![$($dim),+];
-            let data_length = shape.iter().product();
-            Array {
-                data: vec![<$type as Scalar>::Zero; data_length], // syntax <Type as Trait> is used for specifying trait bounds
-                shape,
-                data_order: DataOrder::ColMajor,
-            }
-        }
-    };
-}
-#[macro_export]
-macro_rules! ones {
-    ($type:ty, $($dim:expr),+) => {
-        {
-            use crate::scalar::Scalar;
-            use crate::array_basic::*;
-            let shape = vec![$($dim),+];
-            let data_length = shape.iter().product();
-            Array {
-                data: vec![<$type as Scalar>::One; data_length], // syntax <Type as Trait> is used for specifying trait bounds
-                shape,
-                data_order: DataOrder::ColMajor,
-            }
-        }
-    };
-}
-/// Initialize multi-dimensional array with random numbers
-///
-/// Example usage: `randn!(f64,2,3,4)`
-#[macro_export]
-macro_rules! randn {
-    ($type:ty, $($dim:expr),+) => {
-        {
-            use rand::Rng;
-            use crate::array_basic::*;
-            let mut rng = rand::thread_rng();
-            let shape = [$($dim),+];
-            let data_length = shape.iter().product();
-            Array {
-                data: (0..data_length).map(|_| rng.gen::<$type>()).collect(),
-                shape,
-                data_order: DataOrder::ColMajor,
-            }
-        }
-    };
-}
-
 /// Reshape the multi-dimensional array
 ///
-/// Example usage: `reshape!(m,1,6)` for `m` of `Array<f64>` with shape `[2,3]`
+/// Example usage: `reshape!(m,1,6)` or `reshape!(m,1,2,3)` for `m` of `Array<f64, 2>` with shape `[2,3]`
 #[macro_export]
 macro_rules! reshape {
     ($array:expr, $($dim:expr),+) => {
@@ -0,0 +1,98 @@
+use crate::array_basic::Array;
+use crate::array_error::ShapeError;
+use crate::array_view::ArrayView;
+use crate::scalar::Scalar;
+
+/// Compute the shape of the result of broadcasting two arrays, following julia's rules
+///
+/// Shapes are aligned at the **first** axis, and the missing trailing axes of the shorter shape count as extent one (so a vector acts as a column); an axis of extent one stretches to match the other side. `O` must be the larger of the two dimensions.
+pub fn broadcast_shape<const D1: usize, const D2: usize, const O: usize>(
+    lhs: &[usize; D1],
+    rhs: &[usize; D2],
+) -> Result<[usize; O], ShapeError> {
+    assert_eq!(
+        O,
+        D1.max(D2),
+        "Check Input: broadcasting dimensions {D1} and {D2} gives dimension {}, not {O}!",
+        D1.max(D2)
+    );
+    let mut shape = [1; O];
+    for (axis, extent) in shape.iter_mut().enumerate() {
+        let (a, b) = (
+            lhs.get(axis).copied().unwrap_or(1),
+            rhs.get(axis).copied().unwrap_or(1),
+        );
+        *extent = match (a, b) {
+            _ if a == b => a,
+            (1, _) => b,
+            (_, 1) => a,
+            _ => {
+                return Err(ShapeError::IncompatibleShapes {
+                    lhs: lhs.to_vec(),
+                    rhs: rhs.to_vec(),
+                    axis,
+                })
+            }
+        };
+    }
+    Ok(shape)
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Zero-copy view of the elements stretched to `shape`: axes of extent one (and the missing trailing axes) get stride zero
+    pub fn broadcast_to<const O: usize>(
+        &self,
+        shape: [usize; O],
+    ) -> Result<ArrayView<'a, T, O>, ShapeError> {
+        let error = |axis| ShapeError::IncompatibleShapes {
+            lhs: self.shape.to_vec(),
+            rhs: shape.to_vec(),
+            axis,
+        };
+        if O < D {
+            return Err(error(O));
+        }
+        let mut strides = [0; O];
+        for axis in 0..O {
+            match self.shape.get(axis) {
+                Some(&n) if n == shape[axis] => strides[axis] = self.strides[axis],
+                Some(&1) | None => strides[axis] = 0,
+                Some(_) => return Err(error(axis)),
+            }
+        }
+        Ok(ArrayView {
+            data: self.data,
+            shape,
+            strides,
+            offset: self.offset,
+        })
+    }
+
+    /// Combine two views element by element after broadcasting them to a common shape
+    pub fn broadcast_zip_with<
+        U: Scalar,
+        V: Scalar,
+        F: Fn(T, U) -> V,
+        const E: usize,
+        const O: usize,
+    >(
+        &self,
+        rhs: &ArrayView<'_, U, E>,
+        func: F,
+    ) -> Result<Array<V, O>, ShapeError> {
+        let shape = broadcast_shape(&self.shape, &rhs.shape)?;
+        Ok(self
+            .broadcast_to(shape)?
+            .zip_with(&rhs.broadcast_to(shape)?, func))
+    }
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Zero-copy view of the array stretched to `shape`, see `ArrayView::broadcast_to`
+    pub fn broadcast_to<const O: usize>(
+        &self,
+        shape: [usize; O],
+    ) -> Result<ArrayView<'_, T, O>, ShapeError> {
+        self.view().broadcast_to(shape)
+    }
+}
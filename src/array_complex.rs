@@ -0,0 +1,89 @@
+use crate::array_basic::Array;
+use crate::array_view::ArrayView;
+use crate::scalar::{Conjugate, Scalar};
+use num_complex::ComplexFloat;
+
+/* Element-wise complex functions, which also apply to real arrays */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Complex conjugate of every element, as julia's `conj.(A)`
+    pub fn conj(&self) -> Array<T, D>
+    where
+        T: Conjugate,
+    {
+        self.map(|x| Conjugate::conj(&x))
+    }
+    /// Real part of every element, as julia's `real.(A)`
+    pub fn real(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.map(|x| x.re())
+    }
+    /// Imaginary part of every element (zero for real arrays), as julia's `imag.(A)`
+    pub fn imag(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.map(|x| x.im())
+    }
+    /// Modulus of every element, as julia's `abs.(A)`
+    pub fn abs(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.map(|x| x.abs())
+    }
+    /// Argument of every element in `(-π, π]`, as julia's `angle.(A)`
+    pub fn angle(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.map(|x| x.arg())
+    }
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Complex conjugate of every element, as julia's `conj.(A)`
+    pub fn conj(&self) -> Array<T, D>
+    where
+        T: Conjugate,
+    {
+        self.view().conj()
+    }
+    /// Real part of every element, as julia's `real.(A)`
+    pub fn real(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.view().real()
+    }
+    /// Imaginary part of every element (zero for real arrays), as julia's `imag.(A)`
+    pub fn imag(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.view().imag()
+    }
+    /// Modulus of every element, as julia's `abs.(A)`
+    pub fn abs(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.view().abs()
+    }
+    /// Argument of every element in `(-π, π]`, as julia's `angle.(A)`
+    pub fn angle(&self) -> Array<T::Real, D>
+    where
+        T: ComplexFloat,
+        T::Real: Scalar,
+    {
+        self.view().angle()
+    }
+}
@@ -0,0 +1,191 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_axis, check_same_shape, ShapeError};
+use crate::array_view::{ArrayView, AsArrayView, ColMajorIndices};
+use crate::scalar::Scalar;
+
+/* Concatenation along an existing axis */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Concatenate arrays (or views) along `axis`, as julia's `cat(arrays...; dims)` but with **zero-based** axes
+    ///
+    /// The extents on every other axis must agree. The inputs may be stored in either `DataOrder`; the result is column-major.
+    pub fn cat<V: AsArrayView<T, D>>(axis: usize, arrays: &[V]) -> Array<T, D> {
+        Self::try_cat(axis, arrays).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `cat`, returning `ShapeError::AxisOutOfBounds` or `ShapeError::CatMismatch`
+    pub fn try_cat<V: AsArrayView<T, D>>(
+        axis: usize,
+        arrays: &[V],
+    ) -> Result<Array<T, D>, ShapeError> {
+        check_axis(axis, D)?;
+        let views: Vec<ArrayView<'_, T, D>> = arrays.iter().map(|array| array.as_view()).collect();
+        let Some(first) = views.first() else {
+            return Ok(Array {
+                data: Vec::new(),
+                shape: [0; D],
+                data_order: DataOrder::ColMajor,
+            });
+        };
+        let mut shape = first.shape;
+        shape[axis] = 0;
+        for view in &views {
+            let agrees = (0..D).all(|k| k == axis || view.shape[k] == first.shape[k]);
+            if !agrees {
+                return Err(ShapeError::CatMismatch {
+                    lhs: first.shape.to_vec(),
+                    rhs: view.shape.to_vec(),
+                    axis,
+                });
+            }
+            shape[axis] += view.shape[axis];
+        }
+
+        // In column-major order, the result is a sequence of blocks: for every index on the axes after `axis`, the corresponding block of each input in turn.
+        let mut outer_shape = shape;
+        for extent in outer_shape.iter_mut().take(axis + 1) {
+            *extent = 1;
+        }
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for outer in ColMajorIndices::new(outer_shape) {
+            for view in &views {
+                let mut inner_shape = view.shape;
+                for extent in inner_shape.iter_mut().skip(axis + 1) {
+                    *extent = 1;
+                }
+                for mut indices in ColMajorIndices::new(inner_shape) {
+                    indices[axis + 1..].copy_from_slice(&outer[axis + 1..]);
+                    data.push(view[indices].clone());
+                }
+            }
+        }
+        Ok(Array {
+            data,
+            shape,
+            data_order: DataOrder::ColMajor,
+        })
+    }
+
+    /// Stack arrays (or views) of equal shape along a new `axis` of the result, as julia's `stack(arrays; dims)` but with **zero-based** axes
+    ///
+    /// The dimension `E` of the result must be `D + 1`, and `axis` may range over `0..=D`. Example usage: `let m: Array<f64, 2> = Array::stack(1, &[u, v])` puts the vectors `u` and `v` as columns.
+    pub fn stack<V: AsArrayView<T, D>, const E: usize>(axis: usize, arrays: &[V]) -> Array<T, E> {
+        Self::try_stack(axis, arrays).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `stack`, returning `ShapeError::DimensionMismatch` if `E` is not `D + 1`, `ShapeError::AxisOutOfBounds` or `ShapeError::ShapeMismatch`
+    pub fn try_stack<V: AsArrayView<T, D>, const E: usize>(
+        axis: usize,
+        arrays: &[V],
+    ) -> Result<Array<T, E>, ShapeError> {
+        if E != D + 1 {
+            return Err(ShapeError::DimensionMismatch {
+                expected: D + 1,
+                found: E,
+            });
+        }
+        check_axis(axis, E)?;
+        let views: Vec<ArrayView<'_, T, D>> = arrays.iter().map(|array| array.as_view()).collect();
+        if let Some(first) = views.first() {
+            for view in &views {
+                check_same_shape(&first.shape, &view.shape)?;
+            }
+        }
+        // every input gains an axis of extent one, along which they are concatenated
+        let expanded: Vec<ArrayView<'_, T, E>> = views
+            .iter()
+            .map(|view| {
+                let (mut shape, mut strides) = ([1; E], [0; E]);
+                for k in 0..D {
+                    let target = if k < axis { k } else { k + 1 };
+                    shape[target] = view.shape[k];
+                    strides[target] = view.strides[k];
+                }
+                ArrayView {
+                    data: view.data,
+                    shape,
+                    strides,
+                    offset: view.offset,
+                }
+            })
+            .collect();
+        Array::try_cat(axis, &expanded)
+    }
+    /// Concatenate arrays (or views) horizontally, i.e., along the second axis, taking vectors as columns as julia's `hcat(arrays...)`
+    ///
+    /// Vectors of length `n` are read as matrices of shape `[n, 1]`, so that `Array::hcat(&[&u, &v])` puts the vectors `u` and `v` side by side. To mix vectors with matrices of `n` rows, use `hcat!`.
+    pub fn hcat<V: AsArrayView<T, E>, const E: usize>(arrays: &[V]) -> Array<T, D>
+    where
+        for<'a> ArrayView<'a, T, E>: HcatOperand<'a, T, Operand = ArrayView<'a, T, D>>,
+    {
+        let operands: Vec<ArrayView<'_, T, D>> = arrays
+            .iter()
+            .map(|array| array.as_view().hcat_operand())
+            .collect();
+        Self::cat(1, &operands)
+    }
+}
+
+/// Operand of `hcat!`: vectors become views of shape `[n, 1]`, i.e., columns, as in julia's `hcat`, and arrays of two up to eight dimensions are kept as they are
+pub trait HcatOperand<'a, T: Scalar> {
+    type Operand;
+    fn hcat_operand(self) -> Self::Operand;
+}
+impl<'a, T: Scalar> HcatOperand<'a, T> for ArrayView<'a, T, 1> {
+    type Operand = ArrayView<'a, T, 2>;
+    fn hcat_operand(self) -> Self::Operand {
+        ArrayView {
+            data: self.data,
+            shape: [self.shape[0], 1],
+            strides: [self.strides[0], 0],
+            offset: self.offset,
+        }
+    }
+}
+macro_rules! impl_HcatOperand_for_dims {
+    ($($dim:literal) *) => ($(
+        impl<'a, T: Scalar> HcatOperand<'a, T> for ArrayView<'a, T, $dim> {
+            type Operand = ArrayView<'a, T, $dim>;
+            fn hcat_operand(self) -> Self::Operand {
+                self
+            }
+        }
+    )*)
+}
+impl_HcatOperand_for_dims!(2 3 4 5 6 7 8);
+
+/* Macros */
+/// Concatenate arrays or views along the given **zero-based** axis
+///
+/// Example usage: `cat!(a, b, c; dims = 2)` for three-dimensional `a`, `b` and `c`, which is julia's `cat(a, b, c; dims = 3)`. The keyword may also be spelled `axis = 2`.
+#[macro_export]
+macro_rules! cat {
+    ($($array:expr),+ ; dims = $axis:expr) => {
+        $crate::cat!($($array),+ ; axis = $axis)
+    };
+    ($($array:expr),+ ; axis = $axis:expr) => {
+        {
+            use $crate::array_view::AsArrayView;
+            $crate::array_basic::Array::cat($axis, &[$(($array).as_view()),+])
+        }
+    };
+}
+/// Concatenate arrays or views horizontally, i.e., along the second axis (the columns)
+///
+/// Example usage: `hcat!(a, b)` for matrices `a` and `b` of equal number of rows. As in julia's `hcat`, vectors are taken as columns, so `hcat!(u, v)` of two vectors of length `n` is a matrix of shape `[n, 2]`, and `hcat!(u, m)` prepends the column `u` to the matrix `m`.
+#[macro_export]
+macro_rules! hcat {
+    ($($array:expr),+ $(,)?) => {
+        {
+            use $crate::array_concat::HcatOperand;
+            use $crate::array_view::AsArrayView;
+            $crate::array_basic::Array::cat(1, &[$(($array).as_view().hcat_operand()),+])
+        }
+    };
+}
+/// Concatenate arrays or views vertically, i.e., along the first axis (the rows)
+///
+/// Example usage: `vcat!(a, b)` for matrices `a` and `b` of equal number of columns
+#[macro_export]
+macro_rules! vcat {
+    ($($array:expr),+ $(,)?) => {
+        $crate::cat!($($array),+ ; axis = 0)
+    };
+}
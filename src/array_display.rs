@@ -1,29 +1,16 @@
+use crate::array_aligned::PaddedArray;
 use crate::array_basic::Array;
-use crate::{calculate_data_index, scalar::*};
+use crate::array_dyn::ArrayD;
+use crate::array_shared::ArcArray;
+use crate::array_static::{SArray, SArrayN};
+use crate::array_view::ArrayView;
+use crate::matrix_sparse::SparseCSC;
+use crate::scalar::*;
 // use num_traits::*;
+use std::alloc::Allocator;
 use std::fmt;
 
-// impl<T: Scalar> fmt::Display for Array<T, 1> {
-//     fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
-//         use colored::*;
-//         let eltype = std::any::type_name::<T>();
-//         let array_info = format!("\nArray<{}, {:?}>:", eltype, self.shape).bold();
-//         write!(io, "{}", array_info)?; // print type info
-//         self.display1d(io)
-//     }
-// }
-
-// impl<T: Scalar> fmt::Display for Array<T, 2> {
-//     fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
-//         use colored::*;
-//         let eltype = std::any::type_name::<T>();
-//         let array_info = format!("\nArray<{}, {:?}>:", eltype, self.shape).bold();
-//         write!(io, "{}", array_info)?; // print type info
-//         self.display2d(io)
-//     }
-// }
-
-impl<T: Scalar, const D: usize> fmt::Display for Array<T, D> {
+impl<T: Scalar, const D: usize, A: Allocator> fmt::Display for Array<T, D, A> {
     fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
         use colored::*;
         let eltype = std::any::type_name::<T>();
@@ -33,43 +20,182 @@ impl<T: Scalar, const D: usize> fmt::Display for Array<T, D> {
         )
         .bold();
         write!(io, "{}", array_info)?; // print type info
-        self.display_higher_dimensions(io)
+        self.view().display(io)
     }
 }
 
-impl<T: Scalar> Array<T, 1> {
-    pub fn display1d(&self, io: &mut fmt::Formatter) -> fmt::Result {
+impl<T: Scalar, const D: usize, const ALIGN: usize> fmt::Display for PaddedArray<T, D, ALIGN> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!(
+            "\n{:?} PaddedArray<{}, {:?}> (leading dimension {}):",
+            self.storage.data_order,
+            eltype,
+            self.shape,
+            self.leading_dimension()
+        )
+        .bold();
+        write!(io, "{}", array_info)?; // print type info
+        self.view().display(io)
+    }
+}
+
+impl<T: Scalar, const D: usize> fmt::Display for ArcArray<T, D> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!(
+            "\n{:?} ArcArray<{}, {:?}>:",
+            self.data_order, eltype, self.shape
+        )
+        .bold();
+        write!(io, "{}", array_info)?; // print type info
+        self.view().display(io)
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> fmt::Display for SArray<T, R, C> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!("\nColMajor SArray<{}, {:?}>:", eltype, [R, C]).bold();
+        write!(io, "{}", array_info)?; // print type info
+        self.view().display(io)
+    }
+}
+
+impl<T: Scalar, const N: usize, const D: usize> fmt::Display for SArrayN<T, N, D> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!("\nColMajor SArrayN<{}, {:?}>:", eltype, self.shape).bold();
+        write!(io, "{}", array_info)?; // print type info
+        self.view().display(io)
+    }
+}
+
+/// Only the stored entries are printed, one `[i, j]  =  value` per line in column order, as julia does for sparse matrices
+impl<T: Scalar> fmt::Display for SparseCSC<T> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!(
+            "\nSparseCSC<{}, {:?}> with {} stored entries:",
+            eltype,
+            self.shape,
+            self.nnz()
+        )
+        .bold();
+        write!(io, "{}", array_info)?; // print type info
+        for ([i, j], value) in self.iter() {
+            write!(io, "\n  [{i}, {j}]  =  {}", value.display_string(6))?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> fmt::Display for ArrayView<'a, T, D> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!("\nArrayView<{}, {:?}>:", eltype, self.shape).bold();
+        write!(io, "{}", array_info)?; // print type info
+        self.display(io)
+    }
+}
+
+impl<T: Scalar> fmt::Display for ArrayD<T> {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        use colored::*;
+        let eltype = std::any::type_name::<T>();
+        let array_info = format!(
+            "\n{:?} ArrayD<{}, {:?}>:",
+            self.data_order, eltype, self.shape
+        )
+        .bold();
+        write!(io, "{}", array_info)?; // print type info
+        let strides = self.strides();
+        StridedDisplay {
+            data: &self.data,
+            shape: &self.shape,
+            strides: &strides,
+            offset: 0,
+            element_length: self.get_element_length_and_interval(6, 2),
+        }
+        .display(io)
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    fn display(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        StridedDisplay {
+            data: self.data,
+            shape: &self.shape,
+            strides: &self.strides,
+            offset: self.offset,
+            element_length: self.get_element_length_and_interval(6, 2),
+        }
+        .display(io)
+    }
+}
+
+/// Strided layout with its dimension erased, shared by the display of views and dynamic-dimensional arrays
+struct StridedDisplay<'a, T: Scalar> {
+    data: &'a [T],
+    shape: &'a [usize],
+    strides: &'a [isize],
+    offset: usize,
+    element_length: usize,
+}
+
+impl<'a, T: Scalar> StridedDisplay<'a, T> {
+    const DECIMAL_LENGTH: usize = 6;
+
+    fn element(&self, indices: &[usize]) -> &T {
+        let position = indices
+            .iter()
+            .zip(self.strides.iter())
+            .fold(self.offset as isize, |acc, (&i, &s)| acc + i as isize * s);
+        &self.data[position as usize]
+    }
+
+    /// dispatch the display according to the dimension of the array
+    fn display(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        match self.shape.len() {
+            0 => Ok(()),
+            1 => self.display1d(io),
+            2 => self.display2d(io),
+            _ => self.display_higher_dimensions(io),
+        }
+    }
+
+    fn display1d(&self, io: &mut fmt::Formatter) -> fmt::Result {
         let mut array_string = String::new();
-        for v in self.data.iter() {
-            array_string += format!("\n{:^9.6}", v).as_str();
+        for i in 0..self.shape[0] {
+            array_string += format!(
+                "\n{:^9}",
+                self.element(&[i]).display_string(Self::DECIMAL_LENGTH)
+            )
+            .as_str();
         }
         write!(io, "{}", array_string)?;
         Ok(())
     }
-}
 
-impl<T: Scalar> Array<T, 2> {
     /// display 2d Array
-    pub fn display2d(&self, io: &mut fmt::Formatter) -> fmt::Result {
-        assert!(self.shape.len() == 2);
-
+    fn display2d(&self, io: &mut fmt::Formatter) -> fmt::Result {
         let row = self.shape[0];
         let col = self.shape[1];
 
-        let decimal_length = 6;
-        let element_interval = 2;
-        let element_length = self.get_element_length_and_interval(decimal_length, element_interval);
-
         let mut array_string = String::new();
         for i in 0..row {
             array_string += "\n";
             for j in 0..col {
-                let data_index = calculate_data_index!(self, &[i, j]);
                 array_string += format!(
-                    "{:>element_length$.decimal_length$}",
-                    self.data[data_index],
-                    element_length = element_length,
-                    decimal_length = decimal_length
+                    "{:>element_length$}",
+                    self.element(&[i, j]).display_string(Self::DECIMAL_LENGTH),
+                    element_length = self.element_length
                 )
                 .as_str();
             }
@@ -77,10 +203,7 @@ impl<T: Scalar> Array<T, 2> {
         write!(io, "{}", array_string)?;
         Ok(())
     }
-}
 
-/* implementations for 2d Arrays */
-impl<T: Scalar, const D: usize> Array<T, D> {
     /// display higher dimensional Array
     fn display_higher_dimensions(&self, io: &mut fmt::Formatter) -> fmt::Result {
         let num_of_2d_slices = self.shape.iter().skip(2).product::<usize>(); // skip the first two indices
@@ -88,38 +211,32 @@ impl<T: Scalar, const D: usize> Array<T, D> {
         // Iterate over each 2D slice
         for slice_num in 0..num_of_2d_slices {
             // Compute the indices for the higher dimensions
-            let mut higher_dim_indices = Vec::new();
+            let mut indices = vec![0; self.shape.len()];
 
             let mut remainder = slice_num;
-            for &dim_size in self.shape.iter().skip(2) {
-                higher_dim_indices.push(remainder % dim_size);
+            for (axis, &dim_size) in self.shape.iter().enumerate().skip(2) {
+                indices[axis] = remainder % dim_size;
                 remainder /= dim_size;
             }
 
             // Write the slice header
-            let indices_str = higher_dim_indices
+            let indices_str = indices
                 .iter()
+                .skip(2)
                 .map(usize::to_string)
                 .collect::<Vec<_>>()
                 .join(", ");
             writeln!(io, "\n[:, :, {}] = ", indices_str)?;
 
-            let decimal_length = 6;
-            let element_interval = 2;
-            let element_length =
-                self.get_element_length_and_interval(decimal_length, element_interval);
-
             // Display the 2D slice
             for row in 0..self.shape[0] {
                 for col in 0..self.shape[1] {
-                    let mut indices = vec![row, col];
-                    indices.extend_from_slice(&higher_dim_indices);
-                    let data_index = calculate_data_index!(self, &indices);
+                    indices[0] = row;
+                    indices[1] = col;
                     let elem_string = format!(
-                        "{:>element_length$.decimal_length$}",
-                        self.data[data_index],
-                        element_length = element_length,
-                        decimal_length = decimal_length
+                        "{:>element_length$}",
+                        self.element(&indices).display_string(Self::DECIMAL_LENGTH),
+                        element_length = self.element_length
                     );
                     write!(io, "{elem_string}")?;
                 }
@@ -0,0 +1,388 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_bounds, check_same_shape, check_same_size, ShapeError};
+use crate::array_reduce::{max_nan, min_nan};
+use crate::scalar::{Arithmetic, Field, Scalar};
+use num_traits::FromPrimitive;
+use std::ops::{Add, Div, Index, IndexMut, Mul, Neg, Rem, Sub};
+
+// ### Array Container of Dynamic Dimension
+/// Counterpart of `Array<T, D>` whose dimensionality is only known at runtime: `shape: Vec<usize>` may change its length, e.g., by `reshape` from 2-D to 3-D.
+///
+/// The memory layout follows `data_order` exactly as for `Array<T, D>`, so conversions in both directions move the buffer without copying.
+///
+/// Only the owned core of `Array` is supported: reshaping, indexing, `map`, `to_order`, the whole-array reductions, and the element-wise operators `+ - * / %` and unary `-` between arrays of **equal** shape (without broadcasting) or with a scalar on the right. Views, slicing, reductions along axes and matrix products need a known dimension: convert with `into_fixed` first.
+#[derive(Debug, Clone)]
+pub struct ArrayD<T: Scalar> {
+    pub data: Vec<T>,
+    pub shape: Vec<usize>,
+    pub data_order: DataOrder,
+}
+
+/* Method implementations */
+impl<T: Scalar> ArrayD<T> {
+    /// Number of dimensions
+    pub fn ndim(&self) -> usize {
+        self.shape.len()
+    }
+    /// Per-axis strides of the underlying buffer implied by `data_order`
+    pub fn strides(&self) -> Vec<isize> {
+        self.data_order.dyn_strides(&self.shape)
+    }
+    /// Reshape by clone into any dimension
+    pub fn reshape(&self, new_shape: &[usize]) -> Self {
+        self.try_reshape(new_shape)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `reshape`, returning `ShapeError::SizeMismatch` if the number of elements differs
+    pub fn try_reshape(&self, new_shape: &[usize]) -> Result<Self, ShapeError> {
+        check_same_size(&self.shape, new_shape)?;
+        Ok(ArrayD {
+            data: self.data.clone(),
+            shape: new_shape.to_vec(),
+            data_order: self.data_order,
+        })
+    }
+    /// Reshape in-place into any dimension
+    pub fn reshape_inplace(self, new_shape: &[usize]) -> Self {
+        self.try_reshape_inplace(new_shape)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `reshape_inplace`; the array is dropped on failure
+    pub fn try_reshape_inplace(self, new_shape: &[usize]) -> Result<Self, ShapeError> {
+        check_same_size(&self.shape, new_shape)?;
+        Ok(ArrayD {
+            data: self.data,
+            shape: new_shape.to_vec(),
+            data_order: self.data_order,
+        })
+    }
+    /// Element-wisely Broadcast with closures by clone
+    pub fn map<U: Scalar, F: Fn(T) -> U>(&self, func: F) -> ArrayD<U> {
+        ArrayD {
+            data: self.data.iter().cloned().map(func).collect(),
+            shape: self.shape.clone(),
+            data_order: self.data_order,
+        }
+    }
+    /// Element-wisely Broadcast with closures in-place
+    pub fn map_inplace<U: Scalar, F: Fn(T) -> U>(self, func: F) -> ArrayD<U> {
+        ArrayD {
+            data: self.data.into_iter().map(func).collect(),
+            shape: self.shape,
+            data_order: self.data_order,
+        }
+    }
+    /// Position of the element `[i,j,k,...]` in `data`, respecting `data_order`
+    pub fn data_index(&self, indices: &[usize]) -> usize {
+        self.try_data_index(indices)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `data_index`, returning `ShapeError::DimensionMismatch` or `ShapeError::IndexOutOfBounds`
+    pub fn try_data_index(&self, indices: &[usize]) -> Result<usize, ShapeError> {
+        check_bounds(&self.shape, indices)?;
+        Ok(self.unchecked_data_index(indices))
+    }
+    fn unchecked_data_index(&self, indices: &[usize]) -> usize {
+        strided_position(indices, &self.strides())
+    }
+    fn in_bounds(&self, indices: &[usize]) -> bool {
+        indices.len() == self.ndim() && indices.iter().zip(self.shape.iter()).all(|(i, n)| i < n)
+    }
+    /// Reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get(&self, indices: &[usize]) -> Option<&T> {
+        self.in_bounds(indices)
+            .then(|| &self.data[self.unchecked_data_index(indices)])
+    }
+    /// Mutable reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get_mut(&mut self, indices: &[usize]) -> Option<&mut T> {
+        if !self.in_bounds(indices) {
+            return None;
+        }
+        let data_index = self.unchecked_data_index(indices);
+        Some(&mut self.data[data_index])
+    }
+    /// Get the maximum string length for the element: also work for future symbolic variables!
+    pub fn get_element_length_and_interval(
+        &self,
+        decimal_length: usize,
+        element_interval: usize,
+    ) -> usize {
+        let width = self
+            .data
+            .iter()
+            .map(|x| x.display_string(decimal_length).len())
+            .max()
+            .unwrap_or(0);
+
+        width + element_interval
+    }
+    /// Combine two arrays of equal shape element by element
+    fn zip_with<F: Fn(T, T) -> T>(
+        &self,
+        rhs: &ArrayD<T>,
+        func: F,
+    ) -> Result<ArrayD<T>, ShapeError> {
+        check_same_shape(&self.shape, &rhs.shape)?;
+        if self.data_order == rhs.data_order {
+            return Ok(ArrayD {
+                data: self
+                    .data
+                    .iter()
+                    .zip(rhs.data.iter())
+                    .map(|(a, b)| func(a.clone(), b.clone()))
+                    .collect(),
+                shape: self.shape.clone(),
+                data_order: self.data_order,
+            });
+        }
+        // different layouts: visit both operands in column-major logical order
+        let (lhs_strides, rhs_strides) = (self.strides(), rhs.strides());
+        let mut indices = vec![0; self.ndim()];
+        let mut data = Vec::with_capacity(self.data.len());
+        for _ in 0..self.data.len() {
+            let a = self.data[strided_position(&indices, &lhs_strides)].clone();
+            let b = rhs.data[strided_position(&indices, &rhs_strides)].clone();
+            data.push(func(a, b));
+            advance_indices(&mut indices, &self.shape, DataOrder::ColMajor);
+        }
+        Ok(ArrayD {
+            data,
+            shape: self.shape.clone(),
+            data_order: DataOrder::ColMajor,
+        })
+    }
+    /// Fallible `&A+&B`, returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_add(&self, rhs: &ArrayD<T>) -> Result<ArrayD<T>, ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+    /// Fallible `&A-&B`, returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_sub(&self, rhs: &ArrayD<T>) -> Result<ArrayD<T>, ShapeError>
+    where
+        T: Sub<Output = T>,
+    {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+    /// Fallible `&A*&B` (element-wise), returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_mul(&self, rhs: &ArrayD<T>) -> Result<ArrayD<T>, ShapeError>
+    where
+        T: Mul<Output = T>,
+    {
+        self.zip_with(rhs, |a, b| a * b)
+    }
+    /// Fallible `&A/&B`, returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_div(&self, rhs: &ArrayD<T>) -> Result<ArrayD<T>, ShapeError>
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_with(rhs, |a, b| a / b)
+    }
+    /// Fallible `&A%&B`, returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_rem(&self, rhs: &ArrayD<T>) -> Result<ArrayD<T>, ShapeError>
+    where
+        T: Rem<Output = T>,
+    {
+        self.zip_with(rhs, |a, b| a % b)
+    }
+
+    /// Physically rewrite the buffer into `order` by clone, keeping the logical elements, as `Array::to_order`
+    pub fn to_order(&self, order: DataOrder) -> ArrayD<T> {
+        let strides = self.strides();
+        let mut indices = vec![0; self.ndim()];
+        let mut data = Vec::with_capacity(self.data.len());
+        for _ in 0..self.data.len() {
+            data.push(self.data[strided_position(&indices, &strides)].clone());
+            advance_indices(&mut indices, &self.shape, order);
+        }
+        ArrayD {
+            data,
+            shape: self.shape.clone(),
+            data_order: order,
+        }
+    }
+    /// Physically rewrite the buffer into `order` in-place, which is free when it is already laid out that way
+    pub fn into_order(self, order: DataOrder) -> ArrayD<T> {
+        if self.data_order == order || self.shape.iter().filter(|&&n| n > 1).count() <= 1 {
+            return ArrayD {
+                data_order: order,
+                ..self
+            };
+        }
+        self.to_order(order)
+    }
+}
+
+/* Reductions over the whole array */
+impl<T: Scalar> ArrayD<T> {
+    /// Sum of all elements, `ZERO` for an empty array
+    pub fn sum(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.data.iter().cloned().fold(T::ZERO, |acc, x| acc + x)
+    }
+    /// Product of all elements, `ONE` for an empty array
+    pub fn prod(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.data.iter().cloned().fold(T::ONE, |acc, x| acc * x)
+    }
+    /// Smallest element (`NaN` if any), or `None` for an empty array
+    pub fn minimum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.data.iter().cloned().reduce(min_nan)
+    }
+    /// Largest element (`NaN` if any), or `None` for an empty array
+    pub fn maximum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.data.iter().cloned().reduce(max_nan)
+    }
+    /// Arithmetic mean of all elements
+    pub fn mean(&self) -> T
+    where
+        T: Field + FromPrimitive,
+    {
+        self.sum() / T::from_usize(self.data.len()).expect("the length is representable in `T`")
+    }
+}
+
+/// Step `indices` to the next position within `shape`, the first axis being the fastest for `ColMajor` and the last one for `RowMajor`
+fn advance_indices(indices: &mut [usize], shape: &[usize], order: DataOrder) {
+    let mut step = |(index, &extent): (&mut usize, &usize)| {
+        *index += 1;
+        if *index < extent {
+            return true;
+        }
+        *index = 0;
+        false
+    };
+    match order {
+        DataOrder::ColMajor => indices.iter_mut().zip(shape.iter()).any(&mut step),
+        DataOrder::RowMajor => indices.iter_mut().zip(shape.iter()).rev().any(&mut step),
+    };
+}
+
+/// Position of `[i,j,k,...]` in a packed buffer with the given strides
+#[inline]
+fn strided_position(indices: &[usize], strides: &[isize]) -> usize {
+    indices
+        .iter()
+        .zip(strides.iter())
+        .fold(0isize, |acc, (&i, &s)| acc + i as isize * s) as usize
+}
+
+impl<T: Scalar> Index<&[usize]> for ArrayD<T> {
+    type Output = T;
+    fn index(&self, indices: &[usize]) -> &Self::Output {
+        &self.data[self.data_index(indices)]
+    }
+}
+impl<T: Scalar> IndexMut<&[usize]> for ArrayD<T> {
+    fn index_mut(&mut self, indices: &[usize]) -> &mut Self::Output {
+        let data_index = self.data_index(indices);
+        &mut self.data[data_index]
+    }
+}
+
+/// Implement the element-wise `A+B` for arrays of equal shape: `&A+&B` creates a new array, while `A+B` consumes both and reuses the buffer of `A` when the layouts agree
+macro_rules! impl_elementwise_op_for_ArrayD {
+    ($($trait:ident, $method:ident, $try_method:ident, $op:tt);+ $(;)?) => {$(
+        impl<T: Scalar + $trait<Output = T>> $trait for &ArrayD<T> {
+            type Output = ArrayD<T>;
+            fn $method(self, rhs: Self) -> Self::Output {
+                self.$try_method(rhs).unwrap_or_else(|error| panic!("{error}"))
+            }
+        }
+        impl<T: Scalar + $trait<Output = T>> $trait for ArrayD<T> {
+            type Output = Self;
+            fn $method(mut self, rhs: Self) -> Self::Output {
+                if self.shape != rhs.shape || self.data_order != rhs.data_order {
+                    return &self $op &rhs;
+                }
+                for (a, b) in self.data.iter_mut().zip(rhs.data.into_iter()) {
+                    *a = a.clone() $op b;
+                }
+                self
+            }
+        }
+        /// `&A+x` and `A+x` for a scalar `x` of the element type
+        impl<T: Scalar + $trait<Output = T>> $trait<T> for &ArrayD<T> {
+            type Output = ArrayD<T>;
+            fn $method(self, rhs: T) -> Self::Output {
+                self.map(|a| a $op rhs.clone())
+            }
+        }
+        impl<T: Scalar + $trait<Output = T>> $trait<T> for ArrayD<T> {
+            type Output = Self;
+            fn $method(mut self, rhs: T) -> Self::Output {
+                for a in self.data.iter_mut() {
+                    *a = a.clone() $op rhs.clone();
+                }
+                self
+            }
+        }
+    )+};
+}
+impl_elementwise_op_for_ArrayD!(
+    Add, add, try_add, +;
+    Sub, sub, try_sub, -;
+    Mul, mul, try_mul, *;
+    Div, div, try_div, /;
+    Rem, rem, try_rem, %;
+);
+impl<T: Scalar + Neg<Output = T>> Neg for &ArrayD<T> {
+    type Output = ArrayD<T>;
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+impl<T: Scalar + Neg<Output = T>> Neg for ArrayD<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.map_inplace(|a| -a)
+    }
+}
+
+/* Conversions between fixed and dynamic dimensions */
+impl<T: Scalar, const D: usize> From<Array<T, D>> for ArrayD<T> {
+    fn from(array: Array<T, D>) -> Self {
+        ArrayD {
+            data: array.data,
+            shape: array.shape.to_vec(),
+            data_order: array.data_order,
+        }
+    }
+}
+/// Fails, handing the array back, when its dimension is not `D`
+impl<T: Scalar, const D: usize> TryFrom<ArrayD<T>> for Array<T, D> {
+    type Error = ArrayD<T>;
+    fn try_from(array: ArrayD<T>) -> Result<Self, Self::Error> {
+        match <[usize; D]>::try_from(array.shape.as_slice()) {
+            Ok(shape) => Ok(Array {
+                data: array.data,
+                shape,
+                data_order: array.data_order,
+            }),
+            Err(_) => Err(array),
+        }
+    }
+}
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Convert into an array of dynamic dimension without copying
+    pub fn into_dyn(self) -> ArrayD<T> {
+        ArrayD::from(self)
+    }
+}
+impl<T: Scalar> ArrayD<T> {
+    /// Convert into an array of fixed dimension `D` without copying, handing the array back if its dimension differs
+    pub fn into_fixed<const D: usize>(self) -> Result<Array<T, D>, ArrayD<T>> {
+        Array::try_from(self)
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+/// Reasons why the shapes of the operands make an operation impossible
+///
+/// Every variant carries the shapes involved, and the message of `Display` is the one the panicking counterparts (`reshape`, `+`, `mul_naive`, `[i,j,k,...]`, ...) panic with; the fallible `try_*` methods return it instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The shapes cannot be broadcast together, `axis` being the first axis on which they disagree
+    IncompatibleShapes {
+        lhs: Vec<usize>,
+        rhs: Vec<usize>,
+        axis: usize,
+    },
+    /// The operation needs operands of exactly the same shape
+    ShapeMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+    /// The number of elements differs, e.g., when reshaping
+    SizeMismatch {
+        shape: Vec<usize>,
+        new_shape: Vec<usize>,
+    },
+    /// The columns of the left matrix do not match the rows of the right matrix
+    InnerDimensionMismatch { lhs: Vec<usize>, rhs: Vec<usize> },
+    /// The index on `axis` is not less than its extent
+    IndexOutOfBounds {
+        indices: Vec<usize>,
+        shape: Vec<usize>,
+        axis: usize,
+    },
+    /// The number of axes differs from the dimension of the array
+    DimensionMismatch { expected: usize, found: usize },
+    /// The axis does not exist in an array of dimension `ndim`
+    AxisOutOfBounds { axis: usize, ndim: usize },
+    /// The shapes differ on an axis other than the one to concatenate along
+    CatMismatch {
+        lhs: Vec<usize>,
+        rhs: Vec<usize>,
+        axis: usize,
+    },
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, io: &mut fmt::Formatter) -> fmt::Result {
+        let extent = |shape: &[usize], axis: usize| shape.get(axis).copied().unwrap_or(1);
+        match self {
+            ShapeError::IncompatibleShapes { lhs, rhs, axis } => write!(
+                io,
+                "Check Input: shapes {:?} and {:?} cannot be broadcast together, axis {} has extents {} and {}!",
+                lhs,
+                rhs,
+                axis,
+                extent(lhs, *axis),
+                extent(rhs, *axis)
+            ),
+            ShapeError::ShapeMismatch { lhs, rhs } => {
+                write!(io, "Check Input: shapes {lhs:?} and {rhs:?} differ!")
+            }
+            ShapeError::SizeMismatch { shape, new_shape } => write!(
+                io,
+                "Check Input: cannot reshape {:?} of {} elements into {:?} of {} elements!",
+                shape,
+                shape.iter().product::<usize>(),
+                new_shape,
+                new_shape.iter().product::<usize>()
+            ),
+            ShapeError::InnerDimensionMismatch { lhs, rhs } => write!(
+                io,
+                "Check Input: matrices of shapes {:?} and {:?} cannot be multiplied, inner extents {} and {} differ!",
+                lhs, rhs, lhs[1], rhs[0]
+            ),
+            ShapeError::IndexOutOfBounds {
+                indices,
+                shape,
+                axis,
+            } => write!(
+                io,
+                "Check Input: index {} is out of bounds for axis {} with extent {}!",
+                indices[*axis], axis, shape[*axis]
+            ),
+            ShapeError::DimensionMismatch { expected, found } => write!(
+                io,
+                "Check Input: {found} axes given for an array of dimension {expected}!"
+            ),
+            ShapeError::AxisOutOfBounds { axis, ndim } => write!(
+                io,
+                "Check Input: axis {axis} is out of bounds for an array of dimension {ndim}!"
+            ),
+            ShapeError::CatMismatch { lhs, rhs, axis } => write!(
+                io,
+                "Check Input: shapes {lhs:?} and {rhs:?} cannot be concatenated along axis {axis}!"
+            ),
+        }
+    }
+}
+impl std::error::Error for ShapeError {}
+
+/// `Ok` if the two shapes are equal, otherwise the `ShapeMismatch`
+pub(crate) fn check_same_shape(lhs: &[usize], rhs: &[usize]) -> Result<(), ShapeError> {
+    if lhs == rhs {
+        return Ok(());
+    }
+    Err(ShapeError::ShapeMismatch {
+        lhs: lhs.to_vec(),
+        rhs: rhs.to_vec(),
+    })
+}
+
+/// `Ok` if `new_shape` holds as many elements as `shape`, otherwise the `SizeMismatch`
+pub(crate) fn check_same_size(shape: &[usize], new_shape: &[usize]) -> Result<(), ShapeError> {
+    if shape.iter().product::<usize>() == new_shape.iter().product::<usize>() {
+        return Ok(());
+    }
+    Err(ShapeError::SizeMismatch {
+        shape: shape.to_vec(),
+        new_shape: new_shape.to_vec(),
+    })
+}
+
+/// `Ok` if every index of `[i,j,k,...]` lies within the extent of its axis, otherwise the `IndexOutOfBounds` of the first offending axis
+pub(crate) fn check_bounds(shape: &[usize], indices: &[usize]) -> Result<(), ShapeError> {
+    if indices.len() != shape.len() {
+        return Err(ShapeError::DimensionMismatch {
+            expected: shape.len(),
+            found: indices.len(),
+        });
+    }
+    match indices.iter().zip(shape.iter()).position(|(i, n)| i >= n) {
+        None => Ok(()),
+        Some(axis) => Err(ShapeError::IndexOutOfBounds {
+            indices: indices.to_vec(),
+            shape: shape.to_vec(),
+            axis,
+        }),
+    }
+}
+
+/// `Ok` if `axis` exists in an array of dimension `ndim`, otherwise the `AxisOutOfBounds`
+pub(crate) fn check_axis(axis: usize, ndim: usize) -> Result<(), ShapeError> {
+    if axis < ndim {
+        return Ok(());
+    }
+    Err(ShapeError::AxisOutOfBounds { axis, ndim })
+}
@@ -0,0 +1,263 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_axis, check_bounds, check_same_shape, ShapeError};
+use crate::array_view::{
+    indices_in_order, strided_data_index, ArrayView, ArrayViewMut, AsArrayView,
+};
+use crate::scalar::Scalar;
+use std::alloc::Allocator;
+use std::ops::{Add, Index, IndexMut};
+
+/// Whether every index of `[i,j,k,...]` lies within the extent of its axis
+#[inline]
+fn in_bounds<const D: usize>(shape: &[usize; D], indices: &[usize; D]) -> bool {
+    indices.iter().zip(shape.iter()).all(|(i, n)| i < n)
+}
+
+/* Element access for owned arrays */
+impl<T: Scalar, const D: usize, A: Allocator> Array<T, D, A> {
+    /// Position of the element `[i,j,k,...]` in `data`, respecting `data_order`
+    #[inline]
+    pub fn data_index(&self, indices: &[usize; D]) -> usize {
+        self.try_data_index(indices)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `data_index`, returning `ShapeError::IndexOutOfBounds` for the first offending axis
+    #[inline]
+    pub fn try_data_index(&self, indices: &[usize; D]) -> Result<usize, ShapeError> {
+        check_bounds(&self.shape, indices)?;
+        Ok(strided_data_index(0, &self.strides(), indices))
+    }
+    /// Reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get(&self, indices: [usize; D]) -> Option<&T> {
+        in_bounds(&self.shape, &indices)
+            .then(|| &self.data[strided_data_index(0, &self.strides(), &indices)])
+    }
+    /// Mutable reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get_mut(&mut self, indices: [usize; D]) -> Option<&mut T> {
+        if !in_bounds(&self.shape, &indices) {
+            return None;
+        }
+        let data_index = strided_data_index(0, &self.strides(), &indices);
+        Some(&mut self.data[data_index])
+    }
+}
+impl<T: Scalar, const D: usize, A: Allocator> Index<[usize; D]> for Array<T, D, A> {
+    type Output = T;
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        &self.data[self.data_index(&indices)]
+    }
+}
+impl<T: Scalar, const D: usize, A: Allocator> IndexMut<[usize; D]> for Array<T, D, A> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        let data_index = self.data_index(&indices);
+        &mut self.data[data_index]
+    }
+}
+
+/* Element access for views */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get(&self, indices: [usize; D]) -> Option<&'a T> {
+        in_bounds(&self.shape, &indices).then(|| &self.data[self.data_index(&indices)])
+    }
+}
+impl<'a, T: Scalar, const D: usize> Index<[usize; D]> for ArrayView<'a, T, D> {
+    type Output = T;
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        check_bounds(&self.shape, &indices).unwrap_or_else(|error| panic!("{error}"));
+        &self.data[self.data_index(&indices)]
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get(&self, indices: [usize; D]) -> Option<&T> {
+        in_bounds(&self.shape, &indices).then(|| &self.data[self.data_index(&indices)])
+    }
+    /// Mutable reference to the element `[i,j,k,...]`, or `None` if out of bounds
+    pub fn get_mut(&mut self, indices: [usize; D]) -> Option<&mut T> {
+        if !in_bounds(&self.shape, &indices) {
+            return None;
+        }
+        let data_index = self.data_index(&indices);
+        Some(&mut self.data[data_index])
+    }
+}
+impl<'a, T: Scalar, const D: usize> Index<[usize; D]> for ArrayViewMut<'a, T, D> {
+    type Output = T;
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        check_bounds(&self.shape, &indices).unwrap_or_else(|error| panic!("{error}"));
+        &self.data[self.data_index(&indices)]
+    }
+}
+impl<'a, T: Scalar, const D: usize> IndexMut<[usize; D]> for ArrayViewMut<'a, T, D> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        check_bounds(&self.shape, &indices).unwrap_or_else(|error| panic!("{error}"));
+        let data_index = self.data_index(&indices);
+        &mut self.data[data_index]
+    }
+}
+
+/* Gathering and scattering with index lists along an axis */
+/// Check that `axis` exists and every entry of `indices` lies within its extent
+fn check_axis_indices<const D: usize>(
+    shape: &[usize; D],
+    axis: usize,
+    indices: &[usize],
+) -> Result<(), ShapeError> {
+    check_axis(axis, D)?;
+    match indices.iter().find(|&&i| i >= shape[axis]) {
+        None => Ok(()),
+        Some(&i) => {
+            let mut position = vec![0; D];
+            position[axis] = i;
+            Err(ShapeError::IndexOutOfBounds {
+                indices: position,
+                shape: shape.to_vec(),
+                axis,
+            })
+        }
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Gather the slices at `indices` along `axis` into a new column-major array, like julia's `A[:, indices, :]`
+    ///
+    /// Indices may repeat and come in any order; the extent of `axis` in the result is `indices.len()`.
+    pub fn take(&self, axis: usize, indices: &[usize]) -> Array<T, D> {
+        self.take_in_order(axis, indices, DataOrder::ColMajor)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `take`, returning `ShapeError::AxisOutOfBounds` or `ShapeError::IndexOutOfBounds`
+    pub fn try_take(&self, axis: usize, indices: &[usize]) -> Result<Array<T, D>, ShapeError> {
+        self.take_in_order(axis, indices, DataOrder::ColMajor)
+    }
+    fn take_in_order(
+        &self,
+        axis: usize,
+        indices: &[usize],
+        order: DataOrder,
+    ) -> Result<Array<T, D>, ShapeError> {
+        check_axis_indices(&self.shape, axis, indices)?;
+        let mut shape = self.shape;
+        shape[axis] = indices.len();
+        let data = indices_in_order(shape, order)
+            .map(|mut position| {
+                position[axis] = indices[position[axis]];
+                self.data[self.data_index(&position)].clone()
+            })
+            .collect();
+        Ok(Array {
+            data,
+            shape,
+            data_order: order,
+        })
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Write the slices of `values` to the positions `indices` along `axis`, like julia's `A[:, indices, :] = values`
+    ///
+    /// `values` must have the shape of `self` with the extent of `axis` replaced by `indices.len()`; for repeated indices the last slice wins.
+    pub fn put<V: AsArrayView<T, D>>(&mut self, axis: usize, indices: &[usize], values: &V) {
+        self.try_put(axis, indices, values)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `put`, writing nothing on error
+    pub fn try_put<V: AsArrayView<T, D>>(
+        &mut self,
+        axis: usize,
+        indices: &[usize],
+        values: &V,
+    ) -> Result<(), ShapeError> {
+        self.scatter_with(axis, indices, &values.as_view(), |_, value| value)
+    }
+    /// Add the slices of `values` to the positions `indices` along `axis`, accumulating over repeated indices
+    ///
+    /// `values` must have the shape of `self` with the extent of `axis` replaced by `indices.len()`.
+    pub fn scatter_add<V: AsArrayView<T, D>>(&mut self, axis: usize, indices: &[usize], values: &V)
+    where
+        T: Add<Output = T>,
+    {
+        self.try_scatter_add(axis, indices, values)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `scatter_add`, writing nothing on error
+    pub fn try_scatter_add<V: AsArrayView<T, D>>(
+        &mut self,
+        axis: usize,
+        indices: &[usize],
+        values: &V,
+    ) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.scatter_with(axis, indices, &values.as_view(), |old, value| old + value)
+    }
+    /// Combine every element of `values` into its target element with `func(old, value)`, visiting `values` in its storage order
+    fn scatter_with<F: Fn(T, T) -> T>(
+        &mut self,
+        axis: usize,
+        indices: &[usize],
+        values: &ArrayView<'_, T, D>,
+        func: F,
+    ) -> Result<(), ShapeError> {
+        check_axis_indices(&self.shape, axis, indices)?;
+        let mut expected = self.shape;
+        expected[axis] = indices.len();
+        check_same_shape(&expected, &values.shape)?;
+        let order = values.contiguous_order().unwrap_or(DataOrder::ColMajor);
+        for position in indices_in_order(values.shape, order) {
+            let value = values.data[values.data_index(&position)].clone();
+            let mut target = position;
+            target[axis] = indices[position[axis]];
+            let data_index = self.data_index(&target);
+            self.data[data_index] = func(self.data[data_index].clone(), value);
+        }
+        Ok(())
+    }
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Gather the slices at `indices` along `axis`, see `ArrayView::take`; the result keeps the `data_order` of `self`
+    pub fn take(&self, axis: usize, indices: &[usize]) -> Array<T, D> {
+        self.try_take(axis, indices)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `take`, returning `ShapeError::AxisOutOfBounds` or `ShapeError::IndexOutOfBounds`
+    pub fn try_take(&self, axis: usize, indices: &[usize]) -> Result<Array<T, D>, ShapeError> {
+        self.view().take_in_order(axis, indices, self.data_order)
+    }
+    /// Write the slices of `values` to the positions `indices` along `axis`, see `ArrayViewMut::put`
+    pub fn put<V: AsArrayView<T, D>>(&mut self, axis: usize, indices: &[usize], values: &V) {
+        self.view_mut().put(axis, indices, values)
+    }
+    /// Fallible `put`, writing nothing on error
+    pub fn try_put<V: AsArrayView<T, D>>(
+        &mut self,
+        axis: usize,
+        indices: &[usize],
+        values: &V,
+    ) -> Result<(), ShapeError> {
+        self.view_mut().try_put(axis, indices, values)
+    }
+    /// Add the slices of `values` to the positions `indices` along `axis`, see `ArrayViewMut::scatter_add`
+    pub fn scatter_add<V: AsArrayView<T, D>>(&mut self, axis: usize, indices: &[usize], values: &V)
+    where
+        T: Add<Output = T>,
+    {
+        self.view_mut().scatter_add(axis, indices, values)
+    }
+    /// Fallible `scatter_add`, writing nothing on error
+    pub fn try_scatter_add<V: AsArrayView<T, D>>(
+        &mut self,
+        axis: usize,
+        indices: &[usize],
+        values: &V,
+    ) -> Result<(), ShapeError>
+    where
+        T: Add<Output = T>,
+    {
+        self.view_mut().try_scatter_add(axis, indices, values)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_view::{span, strided_data_index, ArrayView, ArrayViewMut, ColMajorIndices};
+use crate::scalar::Scalar;
+
+/// Either of two iterators over the same items, so that a method can return a fast path and a general path as one type
+enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+impl<A: Iterator, B: Iterator<Item = A::Item>> Iterator for Either<A, B> {
+    type Item = A::Item;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Either::Left(iter) => iter.next(),
+            Either::Right(iter) => iter.next(),
+        }
+    }
+}
+
+/* Element iterators of views */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Iterate the elements in logical **column-major** order (the first index runs fastest), whatever the strides
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        match self.as_slice() {
+            Some(slice) if self.is_contiguous_in(DataOrder::ColMajor) => Either::Left(slice.iter()),
+            _ => Either::Right(self.elements()),
+        }
+    }
+    /// Iterate `([i,j,k,...], &element)` pairs in logical column-major order
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; D], &'a T)> + 'a {
+        let view = *self;
+        ColMajorIndices::new(self.shape)
+            .map(move |indices| (indices, &view.data[view.data_index(&indices)]))
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Iterate the elements mutably in logical **column-major** order, whatever the strides
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.view_mut().into_iter_mut()
+    }
+    /// Iterate `([i,j,k,...], &mut element)` pairs in logical column-major order
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ([usize; D], &mut T)> + '_ {
+        ColMajorIndices::new(self.shape).zip(self.iter_mut())
+    }
+    /// Consume the view into an iterator over its elements, borrowed for the whole lifetime `'a`
+    fn into_iter_mut(self) -> impl Iterator<Item = &'a mut T> + 'a {
+        let len = self.shape.iter().product::<usize>();
+        if self.view().is_contiguous_in(DataOrder::ColMajor) {
+            return Either::Left(self.data[self.offset..self.offset + len].iter_mut());
+        }
+        // walk the strided positions in the order of the logical indices, without touching the rest of the buffer
+        let (shape, strides, offset) = (self.shape, self.strides, self.offset);
+        let (start, end) = span(offset, &shape, &strides);
+        assert!(
+            start <= offset && end <= self.data.len() && has_disjoint_strides(&shape, &strides),
+            "Check Input: the strides of a mutable view must reach distinct elements of its buffer!"
+        );
+        let data = self.data.as_mut_ptr();
+        Either::Right(ColMajorIndices::new(shape).map(move |indices| {
+            // every position lies within the buffer borrowed for `'a`, and distinct indices reach distinct positions, so the references never alias
+            unsafe { &mut *data.add(strided_data_index(offset, &strides, &indices)) }
+        }))
+    }
+}
+
+/// Whether distinct indices always reach distinct positions: taken by increasing magnitude, every stride steps over the whole reach of the smaller ones
+fn has_disjoint_strides<const D: usize>(shape: &[usize; D], strides: &[isize; D]) -> bool {
+    let mut axes: [(usize, usize); D] =
+        std::array::from_fn(|axis| (strides[axis].unsigned_abs(), shape[axis]));
+    axes.sort_unstable();
+    let mut reach = 0;
+    axes.iter().all(|&(stride, n)| {
+        let disjoint = n <= 1 || stride > reach;
+        reach += n.saturating_sub(1) * stride;
+        disjoint
+    })
+}
+
+/* Element iterators of owned arrays */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Iterate the elements in logical **column-major** order, whatever the `data_order`
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.view().iter()
+    }
+    /// Iterate the elements mutably in logical **column-major** order, whatever the `data_order`
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.view_mut().into_iter_mut()
+    }
+    /// Iterate `([i,j,k,...], &element)` pairs in logical column-major order
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; D], &T)> + '_ {
+        self.view().indexed_iter()
+    }
+    /// Iterate `([i,j,k,...], &mut element)` pairs in logical column-major order
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ([usize; D], &mut T)> + '_ {
+        ColMajorIndices::new(self.shape).zip(self.iter_mut())
+    }
+}
+
+/* Axis iterators */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Iterate the one-dimensional lanes along `axis`, i.e., the views obtained by fixing every other index; the fixed indices run in column-major order
+    ///
+    /// Example usage: `m.lanes(0)` iterates the columns of a matrix `m`.
+    pub fn lanes(&self, axis: usize) -> impl Iterator<Item = ArrayView<'a, T, 1>> + 'a {
+        assert!(
+            axis < D,
+            "Check Input: axis {axis} is out of bounds for an array of dimension {D}!"
+        );
+        let view = *self;
+        let mut outer_shape = self.shape;
+        outer_shape[axis] = 1;
+        ColMajorIndices::new(outer_shape).map(move |indices| ArrayView {
+            data: view.data,
+            shape: [view.shape[axis]],
+            strides: [view.strides[axis]],
+            offset: view.data_index(&indices),
+        })
+    }
+    /// Iterate the rows, i.e., the lanes along the **last** axis
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView<'a, T, 1>> + 'a {
+        self.lanes(D - 1)
+    }
+    /// Iterate the columns, i.e., the lanes along the **first** axis
+    pub fn cols(&self) -> impl Iterator<Item = ArrayView<'a, T, 1>> + 'a {
+        self.lanes(0)
+    }
+    /// Iterate the slices for every index along the axes `dims`, like julia's `eachslice(A; dims)`
+    ///
+    /// Each slice keeps the remaining axes in their order, so the dimension `O` of the views must be `D - K`. Example usage: `let s: Vec<ArrayView<f64, 2>> = a.eachslice([2]).collect()` for a 3-D `a`.
+    pub fn eachslice<const K: usize, const O: usize>(
+        &self,
+        dims: [usize; K],
+    ) -> impl Iterator<Item = ArrayView<'a, T, O>> + 'a {
+        assert_eq!(
+            O + K,
+            D,
+            "Check Input: slicing {K} of {D} axes gives dimension {}, not {O}!",
+            D - K
+        );
+        for (k, &axis) in dims.iter().enumerate() {
+            assert!(
+                axis < D && !dims[..k].contains(&axis),
+                "Check Input: dims {dims:?} must be distinct axes of an array of dimension {D}!"
+            );
+        }
+        let kept: Vec<usize> = (0..D).filter(|axis| !dims.contains(axis)).collect();
+        let (mut shape, mut strides) = ([0; O], [0; O]);
+        for (k, &axis) in kept.iter().enumerate() {
+            shape[k] = self.shape[axis];
+            strides[k] = self.strides[axis];
+        }
+        let view = *self;
+        ColMajorIndices::new(dims.map(|axis| self.shape[axis])).map(move |slot| {
+            let offset = slot
+                .iter()
+                .zip(dims.iter())
+                .fold(view.offset as isize, |acc, (&i, &axis)| {
+                    acc + i as isize * view.strides[axis]
+                });
+            ArrayView {
+                data: view.data,
+                shape,
+                strides,
+                offset: offset as usize,
+            }
+        })
+    }
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Iterate the one-dimensional lanes along `axis`, see `ArrayView::lanes`
+    pub fn lanes(&self, axis: usize) -> impl Iterator<Item = ArrayView<'_, T, 1>> + '_ {
+        self.view().lanes(axis)
+    }
+    /// Iterate the rows, i.e., the lanes along the **last** axis
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView<'_, T, 1>> + '_ {
+        self.view().rows()
+    }
+    /// Iterate the columns, i.e., the lanes along the **first** axis
+    pub fn cols(&self) -> impl Iterator<Item = ArrayView<'_, T, 1>> + '_ {
+        self.view().cols()
+    }
+    /// Iterate the slices for every index along the axes `dims`, see `ArrayView::eachslice`
+    pub fn eachslice<const K: usize, const O: usize>(
+        &self,
+        dims: [usize; K],
+    ) -> impl Iterator<Item = ArrayView<'_, T, O>> + '_ {
+        self.view().eachslice(dims)
+    }
+}
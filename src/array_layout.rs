@@ -0,0 +1,196 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_view::{strided_data_index, ArrayView, ArrayViewMut, ColMajorIndices};
+use crate::scalar::Scalar;
+use std::borrow::Cow;
+
+/// Edge length of the square tiles used by the blocked copy kernel: a tile of `f64` (8 KiB) fits comfortably in L1 cache
+const TRANSPOSE_BLOCK: usize = 32;
+
+/* Physical layout queries and conversions for views */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Whether the viewed elements are packed without gaps in the given `order`
+    pub fn is_contiguous_in(&self, order: DataOrder) -> bool {
+        // axes of extent one never advance, so their strides are irrelevant
+        let packed_strides = order.strides(&self.shape);
+        (0..D).all(|axis| self.shape[axis] <= 1 || packed_strides[axis] == self.strides[axis])
+    }
+
+    /// Copy the viewed elements into a new array physically stored in `order`
+    pub fn to_order(self, order: DataOrder) -> Array<T, D> {
+        if let (true, Some(slice)) = (self.is_contiguous_in(order), self.as_slice()) {
+            return Array {
+                data: slice.to_vec(),
+                shape: self.shape,
+                data_order: order,
+            };
+        }
+        let mut data = match self.elements().next() {
+            Some(first) => vec![first.clone(); self.len()], // placeholders, all overwritten below
+            None => Vec::new(),
+        };
+        self.copy_into_order(&mut data, order);
+        Array {
+            data,
+            shape: self.shape,
+            data_order: order,
+        }
+    }
+
+    /// The viewed elements packed in `order`: borrowed when they already are, copied otherwise
+    ///
+    /// Hot loops call this first so that their inner loops run over contiguous slices.
+    pub fn as_contiguous_in(&self, order: DataOrder) -> Cow<'a, [T]> {
+        match (self.is_contiguous_in(order), self.as_slice()) {
+            (true, Some(slice)) => Cow::Borrowed(slice),
+            _ => Cow::Owned(self.to_order(order).data),
+        }
+    }
+
+    /// Cache-blocked copy kernel: write the viewed elements into the packed buffer `dst` laid out in `order`
+    ///
+    /// The plane spanned by the fastest axis of `dst` and the fastest axis of the view is traversed in square tiles, so that neither the reads nor the writes stride across the whole buffer; the remaining axes are looped over outside.
+    fn copy_into_order(&self, dst: &mut [T], order: DataOrder) {
+        if D == 0 {
+            dst.clone_from_slice(&self.data[self.offset..self.offset + 1]);
+            return;
+        }
+        let dst_strides = order.strides(&self.shape);
+        let dst_fast = match order {
+            DataOrder::ColMajor => 0,
+            DataOrder::RowMajor => D - 1,
+        };
+        let src_fast = (0..D)
+            .filter(|&axis| self.shape[axis] > 1)
+            .min_by_key(|&axis| self.strides[axis].unsigned_abs())
+            .unwrap_or(dst_fast);
+
+        let mut outer_shape = self.shape;
+        outer_shape[dst_fast] = 1;
+        outer_shape[src_fast] = 1;
+        for outer_indices in ColMajorIndices::new(outer_shape) {
+            let src_base = self.data_index(&outer_indices) as isize;
+            let dst_base = strided_data_index(0, &dst_strides, &outer_indices) as isize;
+            if src_fast == dst_fast {
+                // both sides run along the same axis: a plain strided copy
+                let (src_step, dst_step) = (self.strides[dst_fast], dst_strides[dst_fast]);
+                for i in 0..self.shape[dst_fast] as isize {
+                    dst[(dst_base + i * dst_step) as usize] =
+                        self.data[(src_base + i * src_step) as usize].clone();
+                }
+                continue;
+            }
+            let (rows, cols) = (self.shape[dst_fast], self.shape[src_fast]);
+            let (src_row_step, src_col_step) = (self.strides[dst_fast], self.strides[src_fast]);
+            let (dst_row_step, dst_col_step) = (dst_strides[dst_fast], dst_strides[src_fast]);
+            for row_block in (0..rows).step_by(TRANSPOSE_BLOCK) {
+                for col_block in (0..cols).step_by(TRANSPOSE_BLOCK) {
+                    for col in col_block..cols.min(col_block + TRANSPOSE_BLOCK) {
+                        let (col, rows_end) = (col as isize, rows.min(row_block + TRANSPOSE_BLOCK));
+                        for row in row_block as isize..rows_end as isize {
+                            dst[(dst_base + row * dst_row_step + col * dst_col_step) as usize] =
+                                self.data
+                                    [(src_base + row * src_row_step + col * src_col_step) as usize]
+                                    .clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/* Physical layout queries and conversions for owned arrays */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Whether `data` is packed in the given `order`, which also holds for both orders when at most one axis is longer than one
+    pub fn is_contiguous_in(&self, order: DataOrder) -> bool {
+        self.view().is_contiguous_in(order)
+    }
+
+    /// Physically rewrite the buffer into `order` by clone, keeping the logical elements
+    ///
+    /// Unlike `DataOrder::alternate`, which only flips the flag, the result is indexed contiguously along the fastest axis of `order`.
+    pub fn to_order(&self, order: DataOrder) -> Array<T, D> {
+        self.view().to_order(order)
+    }
+
+    /// Physically rewrite the buffer into `order` in-place, which is free when it is already laid out that way
+    pub fn into_order(self, order: DataOrder) -> Array<T, D> {
+        if self.is_contiguous_in(order) {
+            return Array {
+                data: self.data,
+                shape: self.shape,
+                data_order: order,
+            };
+        }
+        self.to_order(order)
+    }
+}
+
+/* Permutation of axes */
+/// Panic unless `perm` contains every axis `0..D` exactly once
+fn check_permutation<const D: usize>(perm: &[usize; D]) {
+    let mut seen = [false; D];
+    for &axis in perm {
+        assert!(
+            axis < D && !seen[axis],
+            "Check Input: {perm:?} is not a permutation of the axes 0..{D}!"
+        );
+        seen[axis] = true;
+    }
+}
+/// Rearrange per-axis values such that axis `k` of the result takes axis `perm[k]` of the input
+#[inline]
+fn permute<X: Copy, const D: usize>(values: &[X; D], perm: &[usize; D]) -> [X; D] {
+    perm.map(|axis| values[axis])
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Zero-copy permutation of the axes, as julia's `PermutedDimsArray(A, perm)`: axis `k` of the result is axis `perm[k]` of `self`
+    ///
+    /// Only the shape and strides are permuted, so that the element `[i_0, i_1, ...]` of the result is the element of `self` whose index on axis `perm[k]` is `i_k`.
+    pub fn permutedims_view(&self, perm: [usize; D]) -> ArrayView<'a, T, D> {
+        check_permutation(&perm);
+        ArrayView {
+            data: self.data,
+            shape: permute(&self.shape, &perm),
+            strides: permute(&self.strides, &perm),
+            offset: self.offset,
+        }
+    }
+    /// Permute the axes into a new array by clone, as julia's `permutedims(A, perm)`, keeping the physical `order` given
+    ///
+    /// The copy goes through the cache-blocked kernel of `to_order`, so that the reads and the writes both stay local even when `perm` moves the fastest axis.
+    pub fn permutedims(&self, perm: [usize; D], order: DataOrder) -> Array<T, D> {
+        self.permutedims_view(perm).to_order(order)
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Zero-copy permutation of the axes of a mutable view, writing through to the original elements, see `ArrayView::permutedims_view`
+    pub fn permutedims_view_into(self, perm: [usize; D]) -> ArrayViewMut<'a, T, D> {
+        check_permutation(&perm);
+        ArrayViewMut {
+            shape: permute(&self.shape, &perm),
+            strides: permute(&self.strides, &perm),
+            offset: self.offset,
+            data: self.data,
+        }
+    }
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Zero-copy permutation of the axes, see `ArrayView::permutedims_view`
+    pub fn permutedims_view(&self, perm: [usize; D]) -> ArrayView<'_, T, D> {
+        self.view().permutedims_view(perm)
+    }
+    /// Zero-copy mutable permutation of the axes, see `ArrayView::permutedims_view`
+    pub fn permutedims_view_mut(&mut self, perm: [usize; D]) -> ArrayViewMut<'_, T, D> {
+        self.view_mut().permutedims_view_into(perm)
+    }
+    /// Permute the axes into a new array by clone, as julia's `permutedims(A, perm)`, keeping the `data_order` of `self`
+    ///
+    /// For matrices, `permutedims([1, 0])` gives the same elements as `transpose`, but physically rewrites the buffer instead of flipping `data_order`.
+    pub fn permutedims(&self, perm: [usize; D]) -> Array<T, D> {
+        self.view().permutedims(perm, self.data_order)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::check_same_shape;
+use crate::array_view::{ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::Scalar;
+
+/* Element-wise comparisons */
+/// Implement element-wise comparisons returning boolean masks, like julia's `A .> B`
+///
+/// The right operand is an array, a view or a single scalar of lower or equal dimension, broadcast to the shape of `self`; incompatible shapes panic with `ShapeError::IncompatibleShapes`.
+macro_rules! impl_comparison {
+    ($bound:ident, $($method:ident, $op:tt, $julia:literal);+ $(;)?) => {
+        impl<'a, T: Scalar + $bound, const D: usize> ArrayView<'a, T, D> {
+            $(
+                #[doc = concat!("Element-wise `", $julia, "` as a boolean mask of the shape of `self`")]
+                pub fn $method<V: AsArrayView<T, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+                    let rhs = rhs.as_view();
+                    let rhs = rhs
+                        .broadcast_to(self.shape)
+                        .unwrap_or_else(|error| panic!("{error}"));
+                    self.zip_with(&rhs, |a, b| a $op b)
+                }
+            )+
+        }
+        impl<T: Scalar + $bound, const D: usize> Array<T, D> {
+            $(
+                #[doc = concat!("Element-wise `", $julia, "` as a boolean mask of the shape of `self`")]
+                pub fn $method<V: AsArrayView<T, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+                    self.view().$method(rhs)
+                }
+            )+
+        }
+    };
+}
+impl_comparison!(
+    PartialOrd,
+    gt, >, "A .> B";
+    lt, <, "A .< B";
+    ge, >=, "A .>= B";
+    le, <=, "A .<= B";
+);
+impl_comparison!(
+    PartialEq,
+    eq, ==, "A .== B";
+    ne, !=, "A .!= B";
+);
+
+/* Mask combinators */
+impl<'a, const D: usize> ArrayView<'a, bool, D> {
+    /// Element-wise logical and, like julia's `M .& N`, with `rhs` broadcast to the shape of `self`
+    pub fn and<V: AsArrayView<bool, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+        let rhs = rhs.as_view();
+        let rhs = rhs
+            .broadcast_to(self.shape)
+            .unwrap_or_else(|error| panic!("{error}"));
+        self.zip_with(&rhs, |a, b| a && b)
+    }
+    /// Element-wise logical or, like julia's `M .| N`, with `rhs` broadcast to the shape of `self`
+    pub fn or<V: AsArrayView<bool, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+        let rhs = rhs.as_view();
+        let rhs = rhs
+            .broadcast_to(self.shape)
+            .unwrap_or_else(|error| panic!("{error}"));
+        self.zip_with(&rhs, |a, b| a || b)
+    }
+    /// Element-wise logical negation, like julia's `.!M`
+    pub fn not(&self) -> Array<bool, D> {
+        self.map(|x| !x)
+    }
+    /// Number of `true` elements, like julia's `count(M)`
+    pub fn count(&self) -> usize {
+        self.iter().filter(|&&x| x).count()
+    }
+}
+impl<const D: usize> Array<bool, D> {
+    /// Element-wise logical and, see `ArrayView::and`
+    pub fn and<V: AsArrayView<bool, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+        self.view().and(rhs)
+    }
+    /// Element-wise logical or, see `ArrayView::or`
+    pub fn or<V: AsArrayView<bool, E>, const E: usize>(&self, rhs: &V) -> Array<bool, D> {
+        self.view().or(rhs)
+    }
+    /// Element-wise logical negation, like julia's `.!M`
+    pub fn not(&self) -> Array<bool, D> {
+        self.view().not()
+    }
+    /// Number of `true` elements, like julia's `count(M)`
+    pub fn count(&self) -> usize {
+        self.view().count()
+    }
+}
+
+/* Logical indexing */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Gather the elements where `mask` is `true` in column-major order, like julia's `A[M]`
+    ///
+    /// The mask must have the shape of `self`, otherwise this panics with `ShapeError::ShapeMismatch`.
+    pub fn select<M: AsArrayView<bool, D>>(&self, mask: &M) -> Array<T, 1> {
+        let mask = mask.as_view();
+        check_same_shape(&self.shape, &mask.shape).unwrap_or_else(|error| panic!("{error}"));
+        let data: Vec<T> = self
+            .iter()
+            .zip(mask.iter())
+            .filter(|(_, &selected)| selected)
+            .map(|(x, _)| x.clone())
+            .collect();
+        Array {
+            shape: [data.len()],
+            data,
+            data_order: DataOrder::ColMajor,
+        }
+    }
+}
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Overwrite the elements where `mask` is `true` with `value`, like julia's `A[M] .= value`
+    ///
+    /// The mask must have the shape of `self`, otherwise this panics with `ShapeError::ShapeMismatch`.
+    pub fn assign_where<M: AsArrayView<bool, D>>(&mut self, mask: &M, value: T) {
+        let mask = mask.as_view();
+        check_same_shape(&self.shape, &mask.shape).unwrap_or_else(|error| panic!("{error}"));
+        for (x, &selected) in self.iter_mut().zip(mask.iter()) {
+            if selected {
+                *x = value.clone();
+            }
+        }
+    }
+}
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Gather the elements where `mask` is `true`, see `ArrayView::select`
+    pub fn select<M: AsArrayView<bool, D>>(&self, mask: &M) -> Array<T, 1> {
+        self.view().select(mask)
+    }
+    /// Overwrite the elements where `mask` is `true` with `value`, see `ArrayViewMut::assign_where`
+    pub fn assign_where<M: AsArrayView<bool, D>>(&mut self, mask: &M, value: T) {
+        self.view_mut().assign_where(mask, value)
+    }
+}
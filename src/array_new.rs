@@ -0,0 +1,373 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_same_size, ShapeError};
+use crate::array_view::{indices_in_order, span, ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::{AdditiveIdentity, Arithmetic, MultiplicativeIdentity, RealFloat, Scalar};
+use num_traits::FromPrimitive;
+use std::ops::Sub;
+
+/* Constructors */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Array of the given shape with every element `value`, as julia's `fill(value, dims)`
+    pub fn fill(value: T, shape: [usize; D], data_order: DataOrder) -> Array<T, D> {
+        Array {
+            data: vec![value; shape.iter().product()],
+            shape,
+            data_order,
+        }
+    }
+    /// Array of the given shape with every element zero
+    pub fn zeros(shape: [usize; D], data_order: DataOrder) -> Array<T, D>
+    where
+        T: AdditiveIdentity,
+    {
+        Array::fill(T::ZERO, shape, data_order)
+    }
+    /// Array of the given shape with every element one
+    pub fn ones(shape: [usize; D], data_order: DataOrder) -> Array<T, D>
+    where
+        T: MultiplicativeIdentity,
+    {
+        Array::fill(T::ONE, shape, data_order)
+    }
+    /// Array whose element `[i,j,k,...]` is `func([i,j,k,...])`
+    ///
+    /// `func` is called once per element, in the storage order given by `data_order`.
+    pub fn from_fn<F: FnMut([usize; D]) -> T>(
+        shape: [usize; D],
+        data_order: DataOrder,
+        func: F,
+    ) -> Array<T, D> {
+        Array {
+            data: indices_in_order(shape, data_order).map(func).collect(),
+            shape,
+            data_order,
+        }
+    }
+}
+
+impl<T: AdditiveIdentity + MultiplicativeIdentity> Array<T, 2> {
+    /// Identity matrix of size `n`
+    pub fn eye(n: usize, data_order: DataOrder) -> Array<T, 2> {
+        Array::from_fn(
+            [n, n],
+            data_order,
+            |[i, j]| {
+                if i == j {
+                    T::ONE
+                } else {
+                    T::ZERO
+                }
+            },
+        )
+    }
+}
+
+impl<T: AdditiveIdentity> Array<T, 2> {
+    /// Square matrix with `vector` on the diagonal `offset`, as julia's `diagm(offset => vector)`
+    ///
+    /// A positive `offset` places `vector` above the main diagonal and a negative one below it, so the size is `vector.len() + |offset|`.
+    pub fn diagm<V: AsArrayView<T, 1>>(
+        vector: &V,
+        offset: isize,
+        data_order: DataOrder,
+    ) -> Array<T, 2> {
+        let vector = vector.as_view();
+        let n = vector.shape[0] + offset.unsigned_abs();
+        let (row_shift, col_shift) = if offset < 0 {
+            (offset.unsigned_abs(), 0)
+        } else {
+            (0, offset as usize)
+        };
+        Array::from_fn([n, n], data_order, |[i, j]| {
+            match (i.checked_sub(row_shift), j.checked_sub(col_shift)) {
+                (Some(k), Some(l)) if k == l => vector[[k]].clone(),
+                _ => T::ZERO,
+            }
+        })
+    }
+}
+
+impl<T: Arithmetic + Sub<Output = T> + PartialOrd + FromPrimitive> Array<T, 1> {
+    /// Elements `start, start + step, ...` up to and including `stop`, as julia's `start:step:stop`
+    ///
+    /// Floating-point elements are computed as `start + i * step`, so that rounding errors do not accumulate, and integer elements as the exact `previous + step`; the result is empty if `stop` lies behind `start`. The next element is only computed once it is known not to pass `stop`, so that integer ranges may end at the bounds of their type, e.g., `range(250u8, 3, 255, order)`.
+    pub fn range(start: T, step: T, stop: T, data_order: DataOrder) -> Array<T, 1> {
+        assert!(
+            step != T::ZERO,
+            "Check Input: the step of a range must not be zero!"
+        );
+        let ascending = step > T::ZERO;
+        let within = |x: &T| if ascending { *x <= stop } else { *x >= stop };
+        // whether `x + step` does not pass `stop`, evaluated by the one of `x + step` and `stop - x` that cannot overflow
+        let has_next = |x: &T| {
+            if ascending == (*x <= T::ZERO) {
+                within(&(x.clone() + step.clone()))
+            } else {
+                let rest = stop.clone() - x.clone();
+                if ascending {
+                    rest >= step
+                } else {
+                    rest <= step
+                }
+            }
+        };
+        // `i * step` alone may overflow an integer type when the range crosses zero
+        let is_integer = T::from_f64(0.5) == Some(T::ZERO);
+        let mut data: Vec<T> = Vec::new();
+        if within(&start) {
+            data.push(start.clone());
+        }
+        while let Some(last) = data.last().filter(|x| has_next(x)) {
+            let x = if is_integer {
+                last.clone() + step.clone()
+            } else {
+                let i =
+                    T::from_usize(data.len()).expect("the index of a range fits the element type");
+                start.clone() + i * step.clone()
+            };
+            // `start + i * step` may round past `stop`
+            if !within(&x) {
+                break;
+            }
+            data.push(x);
+        }
+        Array {
+            shape: [data.len()],
+            data,
+            data_order,
+        }
+    }
+}
+
+impl<T: RealFloat + FromPrimitive> Array<T, 1> {
+    /// `n` evenly spaced elements from `start` to `stop`, both included, as julia's `range(start, stop, n)`
+    pub fn linspace(start: T, stop: T, n: usize, data_order: DataOrder) -> Array<T, 1> {
+        let intervals = T::from_usize(n.saturating_sub(1).max(1))
+            .expect("the number of elements fits the element type");
+        Array::from_fn([n], data_order, |[i]| {
+            if i + 1 == n && n > 1 {
+                return stop; // hit the end point exactly
+            }
+            let i = T::from_usize(i).expect("the number of elements fits the element type");
+            start + (stop - start) * i / intervals
+        })
+    }
+}
+
+/* Zero-copy construction from existing buffers */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Take ownership of `data` as the buffer of an array of the given shape, without copying
+    pub fn from_shape_vec(shape: [usize; D], data: Vec<T>, data_order: DataOrder) -> Array<T, D> {
+        Array::try_from_shape_vec(shape, data, data_order).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_shape_vec`, returning `ShapeError::SizeMismatch` if `data` does not hold exactly the elements of `shape`
+    pub fn try_from_shape_vec(
+        shape: [usize; D],
+        data: Vec<T>,
+        data_order: DataOrder,
+    ) -> Result<Array<T, D>, ShapeError> {
+        check_same_size(&[data.len()], &shape)?;
+        Ok(Array {
+            data,
+            shape,
+            data_order,
+        })
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// View a borrowed packed buffer, e.g., a memory-mapped file or a network frame, as an array of the given shape
+    pub fn from_slice(data: &'a [T], shape: [usize; D], data_order: DataOrder) -> Self {
+        ArrayView::try_from_slice(data, shape, data_order).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_slice`, returning `ShapeError::SizeMismatch` if `data` does not hold exactly the elements of `shape`
+    pub fn try_from_slice(
+        data: &'a [T],
+        shape: [usize; D],
+        data_order: DataOrder,
+    ) -> Result<Self, ShapeError> {
+        check_same_size(&[data.len()], &shape)?;
+        Ok(ArrayView {
+            data,
+            shape,
+            strides: data_order.strides(&shape),
+            offset: 0,
+        })
+    }
+    /// View foreign memory with explicit strides (in elements, possibly negative), e.g., a buffer handed over by a C library
+    ///
+    /// `ptr` points to the element `[0,0,...]`.
+    ///
+    /// # Safety
+    /// Every element reachable from `ptr` through `shape` and `strides` must lie in one allocation, be initialized and stay valid and unmodified for the lifetime `'a`; in particular `ptr` must be non-null and aligned. If `shape` contains a zero, no element is reachable and `ptr` is never read, so it may be null or dangling, as C libraries commonly pass for empty buffers.
+    pub unsafe fn from_raw_parts(ptr: *const T, shape: [usize; D], strides: [isize; D]) -> Self {
+        let (offset, len) = raw_layout(&shape, &strides);
+        ArrayView {
+            data: match len {
+                0 => &[],
+                _ => std::slice::from_raw_parts(ptr.sub(offset), len),
+            },
+            shape,
+            strides,
+            offset,
+        }
+    }
+}
+
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// View a borrowed packed buffer mutably as an array of the given shape, so that writes go straight into `data`
+    pub fn from_slice(data: &'a mut [T], shape: [usize; D], data_order: DataOrder) -> Self {
+        ArrayViewMut::try_from_slice(data, shape, data_order)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_slice`, returning `ShapeError::SizeMismatch` if `data` does not hold exactly the elements of `shape`
+    pub fn try_from_slice(
+        data: &'a mut [T],
+        shape: [usize; D],
+        data_order: DataOrder,
+    ) -> Result<Self, ShapeError> {
+        check_same_size(&[data.len()], &shape)?;
+        Ok(ArrayViewMut {
+            data,
+            shape,
+            strides: data_order.strides(&shape),
+            offset: 0,
+        })
+    }
+    /// View foreign memory mutably with explicit strides (in elements, possibly negative)
+    ///
+    /// `ptr` points to the element `[0,0,...]`.
+    ///
+    /// # Safety
+    /// As for `ArrayView::from_raw_parts`; in addition no two indices may reach the same element, and nothing else may access the memory for the lifetime `'a`.
+    pub unsafe fn from_raw_parts_mut(ptr: *mut T, shape: [usize; D], strides: [isize; D]) -> Self {
+        let (offset, len) = raw_layout(&shape, &strides);
+        ArrayViewMut {
+            data: match len {
+                0 => &mut [],
+                _ => std::slice::from_raw_parts_mut(ptr.sub(offset), len),
+            },
+            shape,
+            strides,
+            offset,
+        }
+    }
+}
+
+/// Position of the element `[0,0,...]` within the smallest buffer covering a strided layout, and the length of that buffer, which is zero only for a shape containing a zero
+fn raw_layout<const D: usize>(shape: &[usize; D], strides: &[isize; D]) -> (usize, usize) {
+    if shape.contains(&0) {
+        return (0, 0);
+    }
+    // negative strides reach back in memory before the element `[0,0,...]`
+    let offset = shape
+        .iter()
+        .zip(strides.iter())
+        .filter(|(_, &s)| s < 0)
+        .map(|(&n, &s)| (n - 1) * s.unsigned_abs())
+        .sum();
+    let (start, end) = span(offset, shape, strides);
+    debug_assert_eq!(start, 0);
+    (offset, end)
+}
+
+/* Macros */
+/// Initialize multi-dimensional array with every element zero, column-major unless a `DataOrder` follows a semicolon
+///
+/// Example usage: `zeros!(f64,2,3,4)` or `zeros!(f64,2,3; DataOrder::RowMajor)`
+#[macro_export]
+macro_rules! zeros {
+    ($type:ty, $($dim:expr),+ ; $order:expr) => {
+        $crate::array_basic::Array::fill(<$type as $crate::scalar::AdditiveIdentity>::ZERO, [$($dim),+], $order)
+    };
+    ($type:ty, $($dim:expr),+) => {
+        $crate::zeros!($type, $($dim),+ ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// Initialize multi-dimensional array with every element one, column-major unless a `DataOrder` follows a semicolon
+///
+/// Example usage: `ones!(f64,2,3,4)` or `ones!(f64,2,3; DataOrder::RowMajor)`
+#[macro_export]
+macro_rules! ones {
+    ($type:ty, $($dim:expr),+ ; $order:expr) => {
+        $crate::array_basic::Array::fill(<$type as $crate::scalar::MultiplicativeIdentity>::ONE, [$($dim),+], $order)
+    };
+    ($type:ty, $($dim:expr),+) => {
+        $crate::ones!($type, $($dim),+ ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// Initialize multi-dimensional array with every element `value`, column-major unless a `DataOrder` follows a semicolon
+///
+/// Example usage: `fill!(0.5,2,3)` or `fill!(0.5,2,3; DataOrder::RowMajor)`
+#[macro_export]
+macro_rules! fill {
+    ($value:expr, $($dim:expr),+ ; $order:expr) => {
+        $crate::array_basic::Array::fill($value, [$($dim),+], $order)
+    };
+    ($value:expr, $($dim:expr),+) => {
+        $crate::fill!($value, $($dim),+ ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// Initialize multi-dimensional array from a closure of the indices `[i,j,k,...]`, column-major unless a `DataOrder` follows a semicolon
+///
+/// Example usage: `from_fn!(|[i, j]| i * 10 + j, 2, 3)`
+#[macro_export]
+macro_rules! from_fn {
+    ($func:expr, $($dim:expr),+ ; $order:expr) => {
+        $crate::array_basic::Array::from_fn([$($dim),+], $order, $func)
+    };
+    ($func:expr, $($dim:expr),+) => {
+        $crate::from_fn!($func, $($dim),+ ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// Identity matrix
+///
+/// Example usage: `eye!(f64,3)` or `eye!(f64,3; DataOrder::RowMajor)`
+#[macro_export]
+macro_rules! eye {
+    ($type:ty, $n:expr ; $order:expr) => {
+        $crate::array_basic::Array::<$type, 2>::eye($n, $order)
+    };
+    ($type:ty, $n:expr) => {
+        $crate::eye!($type, $n ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// Square matrix with a vector on one of its diagonals, the main one unless an offset is given
+///
+/// Example usage: `diagm!(v)` or `diagm!(v, -1)` for `v` of `Array<f64, 1>`
+#[macro_export]
+macro_rules! diagm {
+    ($vector:expr, $offset:expr ; $order:expr) => {
+        $crate::array_basic::Array::diagm(&$vector, $offset, $order)
+    };
+    ($vector:expr, $offset:expr) => {
+        $crate::diagm!($vector, $offset ; $crate::array_basic::DataOrder::ColMajor)
+    };
+    ($vector:expr) => {
+        $crate::diagm!($vector, 0)
+    };
+}
+/// One-dimensional array `start:step:stop`, the end point included when it is hit
+///
+/// Example usage: `range!(0.0, 0.25, 1.0)`
+#[macro_export]
+macro_rules! range {
+    ($start:expr, $step:expr, $stop:expr ; $order:expr) => {
+        $crate::array_basic::Array::range($start, $step, $stop, $order)
+    };
+    ($start:expr, $step:expr, $stop:expr) => {
+        $crate::range!($start, $step, $stop ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
+/// One-dimensional array of `n` evenly spaced elements from `start` to `stop`
+///
+/// Example usage: `linspace!(0.0, 1.0, 5)`
+#[macro_export]
+macro_rules! linspace {
+    ($start:expr, $stop:expr, $n:expr ; $order:expr) => {
+        $crate::array_basic::Array::linspace($start, $stop, $n, $order)
+    };
+    ($start:expr, $stop:expr, $n:expr) => {
+        $crate::linspace!($start, $stop, $n ; $crate::array_basic::DataOrder::ColMajor)
+    };
+}
@@ -0,0 +1,416 @@
+use crate::array_aligned::StorageAllocator;
+use crate::array_basic::Array;
+use crate::array_error::ShapeError;
+use crate::array_view::{ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::Scalar;
+use num_complex::Complex;
+use std::alloc::Allocator;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/* Element-wise operators between arrays */
+/// Implement an element-wise binary operator for every by-reference combination of `&Array` and `ArrayView` operands, namely `&A+&B`, `&A+B.view()`, `A.view()+&B` and `A.view()+B.view()` all create a new array and consume neither `A` nor `B`
+///
+/// The operands are broadcast against each other (see `broadcast_shape`), so the dimensions `$d1` / `$d2` may differ and the result has dimension `$o`; incompatible shapes panic with `ShapeError::IncompatibleShapes`.
+macro_rules! impl_elementwise_binary_op_by_ref {
+    ($trait:ident, $method:ident, $op:tt, [$($generics:tt)*] $d1:tt, $d2:tt => $o:tt) => {
+        impl<T: Scalar + $trait<Output = T>, A: Allocator, B: Allocator, $($generics)*>
+            $trait<&Array<T, $d2, B>> for &Array<T, $d1, A>
+        {
+            type Output = Array<T, $o>;
+            fn $method(self, rhs: &Array<T, $d2, B>) -> Self::Output {
+                self.view().$method(rhs.view())
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, A: Allocator, $($generics)*> $trait<ArrayView<'a, T, $d2>>
+            for &Array<T, $d1, A>
+        {
+            type Output = Array<T, $o>;
+            fn $method(self, rhs: ArrayView<'a, T, $d2>) -> Self::Output {
+                self.view().$method(rhs)
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, B: Allocator, $($generics)*> $trait<&Array<T, $d2, B>>
+            for ArrayView<'a, T, $d1>
+        {
+            type Output = Array<T, $o>;
+            fn $method(self, rhs: &Array<T, $d2, B>) -> Self::Output {
+                self.$method(rhs.view())
+            }
+        }
+        impl<'a, 'b, T: Scalar + $trait<Output = T>, $($generics)*> $trait<ArrayView<'b, T, $d2>>
+            for ArrayView<'a, T, $d1>
+        {
+            type Output = Array<T, $o>;
+            fn $method(self, rhs: ArrayView<'b, T, $d2>) -> Self::Output {
+                self.broadcast_zip_with(&rhs, |a, b| a $op b)
+                    .unwrap_or_else(|error| panic!("{error}"))
+            }
+        }
+    };
+}
+
+/// Implement an element-wise binary operator with an **owned** left operand, namely `A+&B`, `A+B.view()` and `A+B` consume `A`
+///
+/// With `reuse`, the resultant is stored in the buffer of `A` whenever `B` can be broadcast to the shape of `A`; otherwise (and with `new`) a new array is created. Either way the result keeps the allocator of `A`, e.g., its alignment.
+macro_rules! impl_elementwise_binary_op_owned_lhs {
+    ($trait:ident, $method:ident, $op:tt, [$($generics:tt)*] $d1:tt, $d2:tt => $o:tt, reuse) => {
+        impl<T: Scalar + $trait<Output = T>, A: StorageAllocator, B: Allocator, $($generics)*>
+            $trait<&Array<T, $d2, B>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(self, rhs: &Array<T, $d2, B>) -> Self::Output {
+                self.$method(rhs.view())
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, A: StorageAllocator, $($generics)*>
+            $trait<ArrayView<'a, T, $d2>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(mut self, rhs: ArrayView<'a, T, $d2>) -> Self::Output {
+                match self
+                    .view_mut()
+                    .zip_assign_with(&rhs, |a, b| *a = a.clone() $op b)
+                {
+                    Ok(()) => self,
+                    Err(_) => {
+                        // the result is larger than `self`
+                        let allocator = self.data.allocator().clone();
+                        (self.view() $op rhs).into_allocator(allocator)
+                    }
+                }
+            }
+        }
+        impl<T: Scalar + $trait<Output = T>, A: StorageAllocator, B: Allocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(self, rhs: Array<T, $d2, B>) -> Self::Output {
+                self.$method(rhs.view())
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $op:tt, [$($generics:tt)*] $d1:tt, $d2:tt => $o:tt, new) => {
+        impl<T: Scalar + $trait<Output = T>, A: StorageAllocator, B: Allocator, $($generics)*>
+            $trait<&Array<T, $d2, B>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(self, rhs: &Array<T, $d2, B>) -> Self::Output {
+                self.$method(rhs.view())
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, A: StorageAllocator, $($generics)*>
+            $trait<ArrayView<'a, T, $d2>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(self, rhs: ArrayView<'a, T, $d2>) -> Self::Output {
+                let allocator = self.data.allocator().clone();
+                (self.view() $op rhs).into_allocator(allocator)
+            }
+        }
+        impl<T: Scalar + $trait<Output = T>, A: StorageAllocator, B: Allocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, A>;
+            fn $method(self, rhs: Array<T, $d2, B>) -> Self::Output {
+                self.$method(rhs.view())
+            }
+        }
+    };
+}
+
+/// Implement an element-wise binary operator with a borrowed left and an **owned** right operand, namely `&A+B` and `A.view()+B` consume `B`
+///
+/// With `reuse`, the resultant is stored in the buffer of `B` whenever `A` can be broadcast to the shape of `B`; otherwise (and with `new`) a new array is created. Either way the result keeps the allocator of `B`.
+macro_rules! impl_elementwise_binary_op_owned_rhs {
+    ($trait:ident, $method:ident, $op:tt, [$($generics:tt)*] $d1:tt, $d2:tt => $o:tt, reuse) => {
+        impl<T: Scalar + $trait<Output = T>, A: Allocator, B: StorageAllocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for &Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, B>;
+            fn $method(self, rhs: Array<T, $d2, B>) -> Self::Output {
+                self.view().$method(rhs)
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, B: StorageAllocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for ArrayView<'a, T, $d1>
+        {
+            type Output = Array<T, $o, B>;
+            fn $method(self, mut rhs: Array<T, $d2, B>) -> Self::Output {
+                match rhs
+                    .view_mut()
+                    .zip_assign_with(&self, |b, a| *b = a $op b.clone())
+                {
+                    Ok(()) => rhs,
+                    Err(_) => {
+                        // the result is larger than `rhs`
+                        let allocator = rhs.data.allocator().clone();
+                        (self $op rhs.view()).into_allocator(allocator)
+                    }
+                }
+            }
+        }
+    };
+    ($trait:ident, $method:ident, $op:tt, [$($generics:tt)*] $d1:tt, $d2:tt => $o:tt, new) => {
+        impl<T: Scalar + $trait<Output = T>, A: Allocator, B: StorageAllocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for &Array<T, $d1, A>
+        {
+            type Output = Array<T, $o, B>;
+            fn $method(self, rhs: Array<T, $d2, B>) -> Self::Output {
+                self.view().$method(rhs)
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, B: StorageAllocator, $($generics)*>
+            $trait<Array<T, $d2, B>> for ArrayView<'a, T, $d1>
+        {
+            type Output = Array<T, $o, B>;
+            fn $method(self, rhs: Array<T, $d2, B>) -> Self::Output {
+                let allocator = rhs.data.allocator().clone();
+                (self $op rhs.view()).into_allocator(allocator)
+            }
+        }
+    };
+}
+
+/// Implement an element-wise binary operator for all operand forms and all pairs of dimensions up to four
+///
+/// The owned operand donates its buffer when it already has the shape of the result, i.e., the left one for `$d1 >= $d2` and the right one for `$d1 <= $d2`.
+macro_rules! impl_elementwise_binary_op {
+    ($trait:ident, $method:ident, $op:tt, $([$($generics:tt)*] $d1:tt, $d2:tt => $o:tt, $lhs:ident, $rhs:ident);+ $(;)?) => {
+        $(
+            impl_elementwise_binary_op_by_ref!($trait, $method, $op, [$($generics)*] $d1, $d2 => $o);
+            impl_elementwise_binary_op_owned_lhs!($trait, $method, $op, [$($generics)*] $d1, $d2 => $o, $lhs);
+            impl_elementwise_binary_op_owned_rhs!($trait, $method, $op, [$($generics)*] $d1, $d2 => $o, $rhs);
+        )+
+    };
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl_elementwise_binary_op!(
+            $trait, $method, $op,
+            [const D: usize] D, D => D, reuse, reuse;
+            [] 1, 2 => 2, new, reuse;
+            [] 2, 1 => 2, reuse, new;
+            [] 1, 3 => 3, new, reuse;
+            [] 3, 1 => 3, reuse, new;
+            [] 2, 3 => 3, new, reuse;
+            [] 3, 2 => 3, reuse, new;
+            [] 1, 4 => 4, new, reuse;
+            [] 4, 1 => 4, reuse, new;
+            [] 2, 4 => 4, new, reuse;
+            [] 4, 2 => 4, reuse, new;
+            [] 3, 4 => 4, new, reuse;
+            [] 4, 3 => 4, reuse, new;
+        );
+    };
+}
+impl_elementwise_binary_op!(Add, add, +);
+impl_elementwise_binary_op!(Sub, sub, -);
+impl_elementwise_binary_op!(Mul, mul, *);
+impl_elementwise_binary_op!(Div, div, /);
+impl_elementwise_binary_op!(Rem, rem, %);
+
+/* Fallible element-wise operators */
+/// Implement `try_add`, `try_sub`, ... next to the operators: the operands are broadcast in the same way, but incompatible shapes give `ShapeError::IncompatibleShapes` instead of a panic
+///
+/// The dimension `O` of the result is the larger of the two, e.g., `let c: Array<f64, 2> = a.try_add(&b)?;`.
+macro_rules! impl_try_elementwise_op {
+    ($($trait:ident, $try_method:ident, $op:tt);+ $(;)?) => {
+        impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+            $(
+                #[doc = concat!("Fallible `", stringify!($op), "` with broadcasting")]
+                pub fn $try_method<V: AsArrayView<T, E>, const E: usize, const O: usize>(
+                    &self,
+                    rhs: &V,
+                ) -> Result<Array<T, O>, ShapeError>
+                where
+                    T: $trait<Output = T>,
+                {
+                    self.broadcast_zip_with(&rhs.as_view(), |a, b| a $op b)
+                }
+            )+
+        }
+        impl<T: Scalar, const D: usize> Array<T, D> {
+            $(
+                #[doc = concat!("Fallible `", stringify!($op), "` with broadcasting")]
+                pub fn $try_method<V: AsArrayView<T, E>, const E: usize, const O: usize>(
+                    &self,
+                    rhs: &V,
+                ) -> Result<Array<T, O>, ShapeError>
+                where
+                    T: $trait<Output = T>,
+                {
+                    self.view().$try_method(rhs)
+                }
+            )+
+        }
+    };
+}
+impl_try_elementwise_op!(
+    Add, try_add, +;
+    Sub, try_sub, -;
+    Mul, try_mul, *;
+    Div, try_div, /;
+    Rem, try_rem, %;
+);
+
+/* Element-wise operators between an array and a scalar */
+/// Implement `A+x` for a scalar `x` of the element type: `&A+x` and `A.view()+x` create a new array, while `A+x` works in the buffer of `A`
+macro_rules! impl_array_scalar_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<T: Scalar + $trait<Output = T>, const D: usize, A: Allocator> $trait<T> for &Array<T, D, A> {
+            type Output = Array<T, D>;
+            fn $method(self, rhs: T) -> Self::Output {
+                self.view().$method(rhs)
+            }
+        }
+        impl<'a, T: Scalar + $trait<Output = T>, const D: usize> $trait<T> for ArrayView<'a, T, D> {
+            type Output = Array<T, D>;
+            fn $method(self, rhs: T) -> Self::Output {
+                self.map(|a| a $op rhs.clone())
+            }
+        }
+        impl<T: Scalar + $trait<Output = T>, const D: usize, A: Allocator> $trait<T> for Array<T, D, A> {
+            type Output = Array<T, D, A>;
+            fn $method(mut self, rhs: T) -> Self::Output {
+                for a in self.data.iter_mut() {
+                    *a = a.clone() $op rhs.clone();
+                }
+                self
+            }
+        }
+    };
+}
+impl_array_scalar_op!(Add, add, +);
+impl_array_scalar_op!(Sub, sub, -);
+impl_array_scalar_op!(Mul, mul, *);
+impl_array_scalar_op!(Div, div, /);
+impl_array_scalar_op!(Rem, rem, %);
+
+/// Implement `x+A` for scalars `x` of the built-in numeric types (a generic `impl for T` is forbidden by the orphan rule)
+macro_rules! impl_scalar_array_op {
+    ($trait:ident, $method:ident, $op:tt, $($type:ty) *) => ($(
+        impl<const D: usize, A: Allocator> $trait<&Array<$type, D, A>> for $type {
+            type Output = Array<$type, D>;
+            fn $method(self, rhs: &Array<$type, D, A>) -> Self::Output {
+                rhs.view().map(|b| self $op b)
+            }
+        }
+        impl<'a, const D: usize> $trait<ArrayView<'a, $type, D>> for $type {
+            type Output = Array<$type, D>;
+            fn $method(self, rhs: ArrayView<'a, $type, D>) -> Self::Output {
+                rhs.map(|b| self $op b)
+            }
+        }
+        impl<const D: usize, A: Allocator> $trait<Array<$type, D, A>> for $type {
+            type Output = Array<$type, D, A>;
+            #[allow(clippy::assign_op_pattern)] // the scalar must stay on the left for `-`, `/` and `%`
+            fn $method(self, mut rhs: Array<$type, D, A>) -> Self::Output {
+                for b in rhs.data.iter_mut() {
+                    *b = self $op *b;
+                }
+                rhs
+            }
+        }
+    )*)
+}
+impl_scalar_array_op!(Add, add, +, usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 Complex<f32> Complex<f64>);
+impl_scalar_array_op!(Sub, sub, -, usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 Complex<f32> Complex<f64>);
+impl_scalar_array_op!(Mul, mul, *, usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 Complex<f32> Complex<f64>);
+impl_scalar_array_op!(Div, div, /, usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 Complex<f32> Complex<f64>);
+impl_scalar_array_op!(Rem, rem, %, usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 Complex<f32> Complex<f64>);
+
+/* Negation */
+/// `-&A` and `-A.view()` create a new array, while `-A` works in the buffer of `A`
+impl<T: Scalar + Neg<Output = T>, const D: usize, A: Allocator> Neg for &Array<T, D, A> {
+    type Output = Array<T, D>;
+    fn neg(self) -> Self::Output {
+        self.view().map(|a| -a)
+    }
+}
+impl<'a, T: Scalar + Neg<Output = T>, const D: usize> Neg for ArrayView<'a, T, D> {
+    type Output = Array<T, D>;
+    fn neg(self) -> Self::Output {
+        self.map(|a| -a)
+    }
+}
+impl<T: Scalar + Neg<Output = T>, const D: usize, A: Allocator> Neg for Array<T, D, A> {
+    type Output = Array<T, D, A>;
+    fn neg(mut self) -> Self::Output {
+        for a in self.data.iter_mut() {
+            *a = -a.clone();
+        }
+        self
+    }
+}
+
+/* Compound assignment */
+/// Implement `A+=B` in-place for arrays and mutable views, where `B` is an array, a view or a scalar
+///
+/// `B` is broadcast to the shape of `A` (so it may have a lower dimension) and no memory is allocated; incompatible shapes panic with `ShapeError::IncompatibleShapes`.
+macro_rules! impl_assign_op {
+    ($trait:ident, $method:ident, $op:tt, $op_trait:ident) => {
+        impl<T: Scalar + $op_trait<Output = T>, const D: usize, const E: usize, A: Allocator, B: Allocator>
+            $trait<&Array<T, E, B>> for Array<T, D, A>
+        {
+            fn $method(&mut self, rhs: &Array<T, E, B>) {
+                self.view_mut().$method(rhs.view());
+            }
+        }
+        impl<'a, T: Scalar + $op_trait<Output = T>, const D: usize, const E: usize, A: Allocator>
+            $trait<ArrayView<'a, T, E>> for Array<T, D, A>
+        {
+            fn $method(&mut self, rhs: ArrayView<'a, T, E>) {
+                self.view_mut().$method(rhs);
+            }
+        }
+        impl<T: Scalar + $op_trait<Output = T>, const D: usize, const E: usize, A: Allocator, B: Allocator>
+            $trait<Array<T, E, B>> for Array<T, D, A>
+        {
+            fn $method(&mut self, rhs: Array<T, E, B>) {
+                self.view_mut().$method(rhs.view());
+            }
+        }
+        impl<T: Scalar + $op_trait<Output = T>, const D: usize, A: Allocator> $trait<T> for Array<T, D, A> {
+            fn $method(&mut self, rhs: T) {
+                for a in self.data.iter_mut() {
+                    *a = a.clone() $op rhs.clone();
+                }
+            }
+        }
+        impl<'a, T: Scalar + $op_trait<Output = T>, const D: usize, const E: usize, B: Allocator>
+            $trait<&Array<T, E, B>> for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: &Array<T, E, B>) {
+                self.$method(rhs.view());
+            }
+        }
+        impl<'a, 'b, T: Scalar + $op_trait<Output = T>, const D: usize, const E: usize>
+            $trait<ArrayView<'b, T, E>> for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: ArrayView<'b, T, E>) {
+                self.zip_assign_with(&rhs, |a, b| *a = a.clone() $op b)
+                    .unwrap_or_else(|error| panic!("{error}"));
+            }
+        }
+        impl<'a, T: Scalar + $op_trait<Output = T>, const D: usize> $trait<T>
+            for ArrayViewMut<'a, T, D>
+        {
+            fn $method(&mut self, rhs: T) {
+                // a zero-dimensional view broadcasts to any shape
+                let rhs = ArrayView {
+                    data: std::slice::from_ref(&rhs),
+                    shape: [],
+                    strides: [],
+                    offset: 0,
+                };
+                self.zip_assign_with(&rhs, |a, b| *a = a.clone() $op b)
+                    .unwrap_or_else(|error| panic!("{error}"));
+            }
+        }
+    };
+}
+impl_assign_op!(AddAssign, add_assign, +, Add);
+impl_assign_op!(SubAssign, sub_assign, -, Sub);
+impl_assign_op!(MulAssign, mul_assign, *, Mul);
+impl_assign_op!(DivAssign, div_assign, /, Div);
+impl_assign_op!(RemAssign, rem_assign, %, Rem);
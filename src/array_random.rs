@@ -0,0 +1,68 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::scalar::Scalar;
+use num_traits::Float;
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use rand_distr::StandardNormal;
+
+/* Random arrays */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Column-major array of independent samples of `distribution`, e.g., `rand_distr::Uniform::new(0, 10)`, `Normal::new(1.0, 0.5)?` or `Exp::new(2.0)?`
+    ///
+    /// The buffer is filled in storage order, so the same seed of `rng` (e.g., `StdRng::seed_from_u64(42)`) always gives the same array.
+    pub fn random<Dist: Distribution<T>, R: Rng + ?Sized>(
+        shape: [usize; D],
+        distribution: Dist,
+        rng: &mut R,
+    ) -> Array<T, D> {
+        Array {
+            data: distribution
+                .sample_iter(rng)
+                .take(shape.iter().product())
+                .collect(),
+            shape,
+            data_order: DataOrder::ColMajor,
+        }
+    }
+    /// Column-major array of uniform samples, on `[0, 1)` for floating-point types and over the whole range for integers
+    pub fn rand<R: Rng + ?Sized>(shape: [usize; D], rng: &mut R) -> Array<T, D>
+    where
+        Standard: Distribution<T>,
+    {
+        Array::random(shape, Standard, rng)
+    }
+    /// Column-major array of standard normal samples, i.e., of mean zero and variance one
+    pub fn randn<R: Rng + ?Sized>(shape: [usize; D], rng: &mut R) -> Array<T, D>
+    where
+        T: Float,
+        StandardNormal: Distribution<T>,
+    {
+        Array::random(shape, StandardNormal, rng)
+    }
+}
+
+/* Macros */
+/// Initialize multi-dimensional array with uniform random numbers, drawn from `thread_rng` unless an explicit `rng = &mut ...` follows a semicolon
+///
+/// Example usage: `rand!(f64,2,3,4)` or `rand!(f64,2,3; rng = &mut StdRng::seed_from_u64(42))`
+#[macro_export]
+macro_rules! rand {
+    ($type:ty, $($dim:expr),+ ; rng = $rng:expr) => {
+        $crate::array_basic::Array::<$type, { [$(stringify!($dim)),+].len() }>::rand([$($dim),+], $rng)
+    };
+    ($type:ty, $($dim:expr),+) => {
+        $crate::rand!($type, $($dim),+ ; rng = &mut ::rand::thread_rng())
+    };
+}
+/// Initialize multi-dimensional array with standard normal random numbers, drawn from `thread_rng` unless an explicit `rng = &mut ...` follows a semicolon
+///
+/// Example usage: `randn!(f64,2,3,4)` or `randn!(f64,2,3; rng = &mut StdRng::seed_from_u64(42))`
+#[macro_export]
+macro_rules! randn {
+    ($type:ty, $($dim:expr),+ ; rng = $rng:expr) => {
+        $crate::array_basic::Array::<$type, { [$(stringify!($dim)),+].len() }>::randn([$($dim),+], $rng)
+    };
+    ($type:ty, $($dim:expr),+) => {
+        $crate::randn!($type, $($dim),+ ; rng = &mut ::rand::thread_rng())
+    };
+}
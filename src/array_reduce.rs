@@ -0,0 +1,287 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::check_axis;
+use crate::array_view::{ArrayView, ColMajorIndices};
+use crate::scalar::{Arithmetic, Field, Scalar};
+use num_traits::FromPrimitive;
+
+/// The smaller of `acc` and `x`, where a `NaN` (anything unordered with itself) wins and then sticks, as julia's `minimum`
+#[inline]
+pub(crate) fn min_nan<T: PartialOrd>(acc: T, x: T) -> T {
+    if x < acc || x.partial_cmp(&x).is_none() {
+        x
+    } else {
+        acc
+    }
+}
+/// The larger of `acc` and `x`, where a `NaN` wins and then sticks, as julia's `maximum`
+#[inline]
+pub(crate) fn max_nan<T: PartialOrd>(acc: T, x: T) -> T {
+    if x > acc || x.partial_cmp(&x).is_none() {
+        x
+    } else {
+        acc
+    }
+}
+
+/* Reductions over the whole array */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Sum of all elements, `ZERO` for an empty view
+    pub fn sum(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.iter().cloned().fold(T::ZERO, |acc, x| acc + x)
+    }
+    /// Product of all elements, `ONE` for an empty view
+    pub fn prod(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.iter().cloned().fold(T::ONE, |acc, x| acc * x)
+    }
+    /// Smallest element (`NaN` if any), or `None` for an empty view
+    pub fn minimum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.iter().cloned().reduce(min_nan)
+    }
+    /// Largest element (`NaN` if any), or `None` for an empty view
+    pub fn maximum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.iter().cloned().reduce(max_nan)
+    }
+    /// Arithmetic mean of all elements
+    pub fn mean(&self) -> T
+    where
+        T: Field + FromPrimitive,
+    {
+        self.sum() / T::from_usize(self.len()).expect("the length is representable in `T`")
+    }
+}
+
+/* Reductions along axes */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Shape of the result of reducing over `dims`: those axes keep extent one
+    ///
+    /// An axis repeated in `dims` is reduced over once, as in julia's `sum(A; dims = (2, 2))`.
+    fn reduced_shape<const K: usize>(&self, dims: &[usize; K]) -> [usize; D] {
+        let mut shape = self.shape;
+        for &axis in dims {
+            check_axis(axis, D).unwrap_or_else(|error| panic!("{error}"));
+            shape[axis] = 1;
+        }
+        shape
+    }
+    /// Apply `map` to every element and combine the results with `op` along the axes `dims`, starting from `init`, as julia's `mapreduce(map, op, A; dims, init)`
+    ///
+    /// The reduced axes are kept with extent one, so that the result broadcasts against `self`. The elements are visited in column-major order for both `DataOrder` layouts.
+    pub fn mapreduce<U: Scalar, M: Fn(T) -> U, F: Fn(U, U) -> U, const K: usize>(
+        &self,
+        map: M,
+        op: F,
+        init: U,
+        dims: [usize; K],
+    ) -> Array<U, D> {
+        self.fold_dims(dims, init, |acc, x| op(acc, map(x)))
+    }
+    /// Fold the elements along a single `axis` with `func`, starting from `init` for every lane
+    pub fn reduce_axis<U: Scalar, F: Fn(U, T) -> U>(
+        &self,
+        axis: usize,
+        init: U,
+        func: F,
+    ) -> Array<U, D> {
+        self.fold_dims([axis], init, func)
+    }
+    fn fold_dims<U: Scalar, F: Fn(U, T) -> U, const K: usize>(
+        &self,
+        dims: [usize; K],
+        init: U,
+        func: F,
+    ) -> Array<U, D> {
+        let shape = self.reduced_shape(&dims);
+        let result = Array {
+            data: vec![init; shape.iter().product()],
+            shape,
+            data_order: DataOrder::ColMajor,
+        };
+        self.accumulate(result, &dims, func)
+    }
+    /// Fold along `dims` starting from the first element of every lane, for reductions without an identity
+    fn fold_dims_from_first<F: Fn(T, T) -> T, const K: usize>(
+        &self,
+        dims: [usize; K],
+        func: F,
+    ) -> Array<T, D> {
+        let shape = self.reduced_shape(&dims);
+        for &axis in &dims {
+            assert!(
+                self.shape[axis] > 0,
+                "Check Input: cannot reduce over axis {axis} of extent zero!"
+            );
+        }
+        let first = Array {
+            data: ColMajorIndices::new(shape)
+                .map(|indices| self[indices].clone())
+                .collect(),
+            shape,
+            data_order: DataOrder::ColMajor,
+        };
+        self.accumulate(first, &dims, func)
+    }
+    /// Fold every element into the entry of `result` at its indices with `dims` set to zero
+    fn accumulate<U: Scalar, F: Fn(U, T) -> U>(
+        &self,
+        mut result: Array<U, D>,
+        dims: &[usize],
+        func: F,
+    ) -> Array<U, D> {
+        for (mut indices, x) in self.indexed_iter() {
+            for &axis in dims {
+                indices[axis] = 0;
+            }
+            let acc = &mut result[indices];
+            *acc = func(acc.clone(), x.clone());
+        }
+        result
+    }
+    /// Sums along the axes `dims`, as julia's `sum(A; dims)`
+    pub fn sum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Arithmetic<T>,
+    {
+        self.fold_dims(dims, T::ZERO, |acc, x| acc + x)
+    }
+    /// Products along the axes `dims`, as julia's `prod(A; dims)`
+    pub fn prod_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Arithmetic<T>,
+    {
+        self.fold_dims(dims, T::ONE, |acc, x| acc * x)
+    }
+    /// Smallest elements along the axes `dims`, as julia's `minimum(A; dims)`
+    pub fn minimum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.fold_dims_from_first(dims, min_nan)
+    }
+    /// Largest elements along the axes `dims`, as julia's `maximum(A; dims)`
+    pub fn maximum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.fold_dims_from_first(dims, max_nan)
+    }
+    /// Means along the axes `dims`, as julia's `mean(A; dims)`
+    pub fn mean_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Field + FromPrimitive,
+    {
+        self.reduced_shape(&dims); // panics on an axis out of bounds
+
+        // the number of elements in every lane, counting a repeated axis once
+        let count: usize = (0..D)
+            .filter(|axis| dims.contains(axis))
+            .map(|axis| self.shape[axis])
+            .product();
+        let count = T::from_usize(count).expect("the length is representable in `T`");
+        self.sum_dims(dims).map_inplace(|x| x / count.clone())
+    }
+}
+
+/* Reductions of owned arrays, see the methods of `ArrayView` */
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Sum of all elements, `ZERO` for an empty array
+    pub fn sum(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.view().sum()
+    }
+    /// Product of all elements, `ONE` for an empty array
+    pub fn prod(&self) -> T
+    where
+        T: Arithmetic<T>,
+    {
+        self.view().prod()
+    }
+    /// Smallest element (`NaN` if any), or `None` for an empty array
+    pub fn minimum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.view().minimum()
+    }
+    /// Largest element (`NaN` if any), or `None` for an empty array
+    pub fn maximum(&self) -> Option<T>
+    where
+        T: PartialOrd,
+    {
+        self.view().maximum()
+    }
+    /// Arithmetic mean of all elements
+    pub fn mean(&self) -> T
+    where
+        T: Field + FromPrimitive,
+    {
+        self.view().mean()
+    }
+    /// Map and reduce along the axes `dims`, see `ArrayView::mapreduce`
+    pub fn mapreduce<U: Scalar, M: Fn(T) -> U, F: Fn(U, U) -> U, const K: usize>(
+        &self,
+        map: M,
+        op: F,
+        init: U,
+        dims: [usize; K],
+    ) -> Array<U, D> {
+        self.view().mapreduce(map, op, init, dims)
+    }
+    /// Fold the elements along a single `axis`, see `ArrayView::reduce_axis`
+    pub fn reduce_axis<U: Scalar, F: Fn(U, T) -> U>(
+        &self,
+        axis: usize,
+        init: U,
+        func: F,
+    ) -> Array<U, D> {
+        self.view().reduce_axis(axis, init, func)
+    }
+    /// Sums along the axes `dims`, as julia's `sum(A; dims)`
+    pub fn sum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Arithmetic<T>,
+    {
+        self.view().sum_dims(dims)
+    }
+    /// Products along the axes `dims`, as julia's `prod(A; dims)`
+    pub fn prod_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Arithmetic<T>,
+    {
+        self.view().prod_dims(dims)
+    }
+    /// Smallest elements along the axes `dims`, as julia's `minimum(A; dims)`
+    pub fn minimum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.view().minimum_dims(dims)
+    }
+    /// Largest elements along the axes `dims`, as julia's `maximum(A; dims)`
+    pub fn maximum_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: PartialOrd,
+    {
+        self.view().maximum_dims(dims)
+    }
+    /// Means along the axes `dims`, as julia's `mean(A; dims)`
+    pub fn mean_dims<const K: usize>(&self, dims: [usize; K]) -> Array<T, D>
+    where
+        T: Field + FromPrimitive,
+    {
+        self.view().mean_dims(dims)
+    }
+}
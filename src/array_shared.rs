@@ -0,0 +1,127 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_bounds, check_same_size, ShapeError};
+use crate::array_view::{strided_data_index, ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::Scalar;
+use std::ops::{Index, IndexMut};
+use std::sync::Arc;
+
+// ### Array with Shared Copy-on-Write Storage
+/// Array whose buffer is reference-counted, so that `clone()` only bumps a counter instead of copying `data`
+///
+/// Clones share the buffer until one of them is written: `view_mut()`, `IndexMut` and the other mutating methods first copy the buffer if it is shared (copy-on-write), so a write is never seen by the other clones. With `T: Send + Sync` the array is `Send + Sync`, and clones may be handed to other threads, e.g., `rayon` tasks.
+#[derive(Debug, Clone)]
+pub struct ArcArray<T: Scalar, const D: usize> {
+    pub data: Arc<Vec<T>>,
+    pub shape: [usize; D],
+    pub data_order: DataOrder,
+}
+
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Move the buffer into shared storage without copying the elements
+    pub fn into_shared(self) -> ArcArray<T, D> {
+        ArcArray {
+            data: Arc::new(self.data),
+            shape: self.shape,
+            data_order: self.data_order,
+        }
+    }
+}
+impl<T: Scalar, const D: usize> From<Array<T, D>> for ArcArray<T, D> {
+    fn from(array: Array<T, D>) -> Self {
+        array.into_shared()
+    }
+}
+
+impl<T: Scalar, const D: usize> ArcArray<T, D> {
+    /// Take the buffer back into an `Array`, copying it only if it is still shared with other clones
+    pub fn into_owned(self) -> Array<T, D> {
+        Array {
+            data: Arc::unwrap_or_clone(self.data),
+            shape: self.shape,
+            data_order: self.data_order,
+        }
+    }
+    /// Whether no other clone shares the buffer, i.e., whether a write happens without copy
+    pub fn is_unique(&self) -> bool {
+        Arc::strong_count(&self.data) == 1
+    }
+    /// Whether the two arrays share one buffer
+    pub fn shares_data_with<const E: usize>(&self, other: &ArcArray<T, E>) -> bool {
+        Arc::ptr_eq(&self.data, &other.data)
+    }
+    /// Position of the element `[i,j,k,...]` in `data`, respecting `data_order`
+    #[inline]
+    pub fn data_index(&self, indices: &[usize; D]) -> usize {
+        check_bounds(&self.shape, indices).unwrap_or_else(|error| panic!("{error}"));
+        strided_data_index(0, &self.strides(), indices)
+    }
+    /// Per-axis strides of the buffer in its `data_order`
+    pub fn strides(&self) -> [isize; D] {
+        self.data_order.strides(&self.shape)
+    }
+    /// Borrow the whole array as a read-only view
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView {
+            data: &self.data,
+            shape: self.shape,
+            strides: self.strides(),
+            offset: 0,
+        }
+    }
+    /// Borrow the whole array as a mutable view, copying the buffer first if it is shared
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        let strides = self.strides();
+        ArrayViewMut {
+            data: Arc::make_mut(&mut self.data).as_mut_slice(),
+            shape: self.shape,
+            strides,
+            offset: 0,
+        }
+    }
+    /// Reshape, possibly into a different dimension, sharing the buffer with `self`
+    ///
+    /// The data is reinterpreted in its own `data_order`, as `Array::reshape`.
+    pub fn reshape<const E: usize>(&self, new_shape: [usize; E]) -> ArcArray<T, E> {
+        self.try_reshape(new_shape)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `reshape`, returning `ShapeError::SizeMismatch` if the number of elements differs
+    pub fn try_reshape<const E: usize>(
+        &self,
+        new_shape: [usize; E],
+    ) -> Result<ArcArray<T, E>, ShapeError> {
+        check_same_size(&self.shape, &new_shape)?;
+        Ok(ArcArray {
+            data: Arc::clone(&self.data),
+            shape: new_shape,
+            data_order: self.data_order,
+        })
+    }
+    /// Element-wisely Broadcast with closures into a new buffer
+    pub fn map<U: Scalar, F: Fn(T) -> U>(&self, func: F) -> ArcArray<U, D> {
+        self.view().map(func).into_shared()
+    }
+    /// Element-wisely update in-place, copying the buffer first if it is shared
+    pub fn map_mut<F: Fn(&mut T)>(&mut self, func: F) {
+        Arc::make_mut(&mut self.data).iter_mut().for_each(func);
+    }
+}
+
+impl<T: Scalar, const D: usize> AsArrayView<T, D> for ArcArray<T, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+impl<T: Scalar, const D: usize> Index<[usize; D]> for ArcArray<T, D> {
+    type Output = T;
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        &self.data[self.data_index(&indices)]
+    }
+}
+/// Copy-on-write: the buffer is copied first if it is shared with other clones
+impl<T: Scalar, const D: usize> IndexMut<[usize; D]> for ArcArray<T, D> {
+    fn index_mut(&mut self, indices: [usize; D]) -> &mut Self::Output {
+        let data_index = self.data_index(&indices);
+        &mut Arc::make_mut(&mut self.data)[data_index]
+    }
+}
@@ -1,62 +1,347 @@
 use crate::array_basic::*;
+use crate::array_view::{ArrayView, ArrayViewMut};
 use crate::scalar::*;
 
-use std::ops::{Index, IndexMut, Range, RangeFull};
+/// Position along an axis, counted either from the first slot or backwards from the last slot (julia's `end`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bound {
+    Start(usize), // `i`, counted from the first slot
+    End(usize),   // `end - k`, counted backwards from the last slot
+}
+impl Bound {
+    /// Resolve to a signed position for an axis of the given extent (negative when `end - k` underflows)
+    #[inline]
+    fn resolve(self, extent: usize) -> isize {
+        match self {
+            Bound::Start(i) => i as isize,
+            Bound::End(k) => extent as isize - 1 - k as isize,
+        }
+    }
+}
 
+#[derive(Debug, Clone, Copy)]
 pub enum Axes {
     FullAxis,            // the full axis
-    Range(usize, usize), // a range (start, end) in the axis
-    Slot(usize),         // a single slot in the axis
+    Range(usize, usize), // a range (start, end) in the axis, both ends included
+    StepRange {
+        start: Bound,
+        stop: Bound,
+        step: isize,
+        inclusive: bool,
+    }, // a stepped range `start, start + step, ...` up to `stop` (included only when `inclusive`); negative steps walk backwards
+    Slot(usize),        // a single slot in the axis
+    SlotFromEnd(usize), // a single slot counted backwards from the last one, i.e., `end - k`
 }
 
-impl<T: Scalar> Array<T> {
-    #[inline]
-    fn check_slice_legitimacy(&self, slices: &[Axes]) {
-        assert_eq!(self.shape.len(), slices.len());
+/// How a single axis is transformed by its `Axes` indicator
+enum ResolvedAxis {
+    Kept {
+        start: usize,
+        length: usize,
+        step: isize,
+    },
+    Dropped(usize),
+}
+
+impl Axes {
+    /// Resolve the indicator against the extent of `axis`, panicking on out-of-bound slots
+    fn resolve(&self, axis: usize, extent: usize) -> ResolvedAxis {
+        let check_slot = |i: isize| {
+            assert!(
+                0 <= i && i < extent as isize,
+                "Check Input: slot {i} is out of bounds for axis {axis} with extent {extent}!"
+            );
+            i as usize
+        };
+        match *self {
+            Axes::FullAxis => ResolvedAxis::Kept {
+                start: 0,
+                length: extent,
+                step: 1,
+            },
+            Axes::Range(m, n) => {
+                assert!(
+                    m <= n,
+                    "Check Input: empty range {m}..={n} for axis {axis}!"
+                );
+                check_slot(n as isize);
+                ResolvedAxis::Kept {
+                    start: m,
+                    length: n - m + 1,
+                    step: 1,
+                }
+            }
+            Axes::StepRange {
+                start,
+                stop,
+                step,
+                inclusive,
+            } => {
+                assert!(step != 0, "Check Input: zero step for axis {axis}!");
+                let start = start.resolve(extent);
+                let mut stop = stop.resolve(extent);
+                if inclusive {
+                    stop += step.signum();
+                }
+                let distance = (stop - start) * step.signum();
+                let length = if distance > 0 {
+                    (distance + step.abs() - 1) / step.abs()
+                } else {
+                    0
+                };
+                if length == 0 {
+                    return ResolvedAxis::Kept {
+                        start: 0,
+                        length: 0,
+                        step,
+                    };
+                }
+                check_slot(start + (length - 1) * step); // the last visited slot
+                ResolvedAxis::Kept {
+                    start: check_slot(start),
+                    length: length as usize,
+                    step,
+                }
+            }
+            Axes::Slot(i) => ResolvedAxis::Dropped(check_slot(i as isize)),
+            Axes::SlotFromEnd(k) => {
+                ResolvedAxis::Dropped(check_slot(Bound::End(k).resolve(extent)))
+            }
+        }
     }
+}
 
-    pub fn slice_view(&self, slices: &[Axes]) -> &Array<T> {
-        self.check_slice_legitimacy(slices);
+/// Slice indicators for an array of dimension `IN` that produce a view of dimension `OUT`
+///
+/// Every `Axes::Slot` / `Axes::SlotFromEnd` drops its axis, so `OUT` is the number of the other indicators. The `s!` macro counts them while expanding, which gives the sliced result its dimension at compile time, e.g., `s![0, .., ..]` is a `SliceInfo<3, 2>`.
+#[derive(Debug, Clone, Copy)]
+pub struct SliceInfo<const IN: usize, const OUT: usize> {
+    axes: [Axes; IN],
+}
+impl<const IN: usize, const OUT: usize> SliceInfo<IN, OUT> {
+    /// Check that `axes` keep exactly `OUT` axes
+    pub fn new(axes: [Axes; IN]) -> Self {
+        let kept_axes = axes
+            .iter()
+            .filter(|s| !matches!(s, Axes::Slot(_) | Axes::SlotFromEnd(_)))
+            .count();
+        assert_eq!(
+            kept_axes, OUT,
+            "Check Input: the slices keep {kept_axes} axes but a view of dimension {OUT} is requested!"
+        );
+        SliceInfo { axes }
+    }
+    pub fn axes(&self) -> &[Axes; IN] {
+        &self.axes
+    }
+}
 
-        todo!()
+/// Apply the slice indicators to a strided layout `(shape, strides, offset)` and return the layout of the sliced view
+fn compute_sliced_layout<const D: usize, const O: usize>(
+    shape: &[usize; D],
+    strides: &[isize; D],
+    offset: usize,
+    info: &SliceInfo<D, O>,
+) -> ([usize; O], [isize; O], usize) {
+    let mut new_shape = [0; O];
+    let mut new_strides = [0; O];
+    let mut new_offset = offset as isize;
+    let mut new_axis = 0;
+    for (axis, slice_indicator) in info.axes.iter().enumerate() {
+        match slice_indicator.resolve(axis, shape[axis]) {
+            ResolvedAxis::Kept {
+                start,
+                length,
+                step,
+            } => {
+                new_offset += start as isize * strides[axis];
+                new_shape[new_axis] = length;
+                new_strides[new_axis] = strides[axis] * step;
+                new_axis += 1;
+            }
+            ResolvedAxis::Dropped(i) => new_offset += i as isize * strides[axis],
+        }
     }
+    (new_shape, new_strides, new_offset as usize)
+}
 
-    pub fn slice(&mut self, slices: &[Axes]) -> Array<T> {
-        self.check_slice_legitimacy(slices);
+impl<T: Scalar, const D: usize> Array<T, D> {
+    /// Zero-copy slice of the array, e.g., `m.slice_view(&s![.., 1..3, 2])` is an `ArrayView<T, 2>` for `m` of `Array<T, 3>`
+    pub fn slice_view<const O: usize>(&self, info: &SliceInfo<D, O>) -> ArrayView<'_, T, O> {
+        self.view().slice_view(info)
+    }
 
-        todo!()
+    /// Zero-copy mutable slice of the array
+    pub fn slice_view_mut<const O: usize>(
+        &mut self,
+        info: &SliceInfo<D, O>,
+    ) -> ArrayViewMut<'_, T, O> {
+        self.view_mut().slice_view_into(info)
     }
 
-    fn compute_slice_indicator_vector(&self, slices: &[Axes]) -> Vec<usize> {
-        let mut slice_indicator_vector = Vec::<usize>::new();
-        for (i, slice_indicator) in slices.iter().enumerate() {
-            match slice_indicator {
-                Axes::FullAxis => slice_indicator_vector.push(self.shape[i]),
-                Axes::Range(m, n) => {
-                    assert!(m <= n);
-                    slice_indicator_vector.push(n - m + 1)
-                }
-                Axes::Slot(_) => slice_indicator_vector.push(0), // use `0` as placeholder for current axis with single slot
-            }
-        }
-        slice_indicator_vector
+    /// Slice of the array copied into a new owned array
+    pub fn slice<const O: usize>(&self, info: &SliceInfo<D, O>) -> Array<T, O> {
+        self.slice_view(info).to_owned()
     }
+}
 
-    fn compute_new_shape(&self, slices: &[Axes]) -> Vec<usize> {
-        let slice_indicator_vector = self.compute_slice_indicator_vector(slices);
-        slice_indicator_vector
-            .into_iter()
-            .filter(|&x| x != 0)
-            .collect()
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Zero-copy slice of the view
+    pub fn slice_view<const O: usize>(&self, info: &SliceInfo<D, O>) -> ArrayView<'a, T, O> {
+        let (shape, strides, offset) =
+            compute_sliced_layout(&self.shape, &self.strides, self.offset, info);
+        ArrayView {
+            data: self.data,
+            shape,
+            strides,
+            offset,
+        }
     }
+}
 
-    fn extract_data_from_slices(&self, slices: &[Axes]) -> Vec<T> {
-        let new_shape = self.compute_new_shape(slices);
-        let new_data = Vec::<T>::with_capacity(new_shape.iter().product());
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Zero-copy mutable slice, reborrowing the view
+    pub fn slice_view_mut<const O: usize>(
+        &mut self,
+        info: &SliceInfo<D, O>,
+    ) -> ArrayViewMut<'_, T, O> {
+        self.view_mut().slice_view_into(info)
+    }
 
-        self.data
-            .iter()
-            .step_by(new_shape.into_iter().min().unwrap());
-        todo!();
+    /// Zero-copy mutable slice, consuming the view
+    pub fn slice_view_into<const O: usize>(self, info: &SliceInfo<D, O>) -> ArrayViewMut<'a, T, O> {
+        let (shape, strides, offset) =
+            compute_sliced_layout(&self.shape, &self.strides, self.offset, info);
+        ArrayViewMut {
+            data: self.data,
+            shape,
+            strides,
+            offset,
+        }
     }
 }
+
+/* Macros */
+/// Build the slice indicators `SliceInfo` for `slice_view` / `slice` from julia-like syntax
+///
+/// Each comma-separated entry is one of
+/// - `..` for the full axis;
+/// - `a..b` / `a..=b` for a range (the end is excluded / included), either bound may be omitted;
+/// - `a..b;step` for a stepped range, where a negative `step` walks backwards, e.g., `..;-1` reverses the axis;
+/// - `i` for a single slot, which drops the axis.
+///
+/// Julia's `end` (the last slot) may be used for any bound or slot in the forms `end` and `end - k`.
+///
+/// The dimension of the sliced result is the number of entries that are not single slots, and is known at compile time.
+///
+/// Example usage: `m.slice_view(&s![.., 1..3, end])` is an `ArrayView<T, 2>` for `m` of `Array<T, 3>`
+#[macro_export]
+macro_rules! s {
+    // count the entries and the kept (non-slot) axes: `[entries] [kept axes] [started kept]` for the current entry
+    (@count [$($in:tt)*] [$($out:tt)*] [$started:tt $kept:tt] , $($rest:tt)*) => {
+        $crate::s!(@count [$($in)* $started] [$($out)* $kept] [0 0] $($rest)*)
+    };
+    (@count [$($in:tt)*] [$($out:tt)*] [$started:tt $kept:tt] .. $($rest:tt)*) => {
+        $crate::s!(@count [$($in)*] [$($out)*] [1 1] $($rest)*)
+    };
+    (@count [$($in:tt)*] [$($out:tt)*] [$started:tt $kept:tt] ..= $($rest:tt)*) => {
+        $crate::s!(@count [$($in)*] [$($out)*] [1 1] $($rest)*)
+    };
+    (@count [$($in:tt)*] [$($out:tt)*] [$started:tt $kept:tt] $next:tt $($rest:tt)*) => {
+        $crate::s!(@count [$($in)*] [$($out)*] [1 $kept] $($rest)*)
+    };
+    (@count [$($in:tt)*] [$($out:tt)*] [$started:tt $kept:tt]) => {
+        $crate::array_slice::SliceInfo<{ 0 $(+ $in)* + $started }, { 0 $(+ $out)* + $kept }>
+    };
+
+    // split the input at top-level commas: `[parsed axes] [tokens of current axis] rest`
+    (@split [$($parsed:expr,)*] [$($current:tt)+] , $($rest:tt)*) => {
+        $crate::s!(@split [$($parsed,)* $crate::s!(@axis [] $($current)+),] [] $($rest)*)
+    };
+    (@split [$($parsed:expr,)*] [$($current:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::s!(@split [$($parsed,)*] [$($current)* $next] $($rest)*)
+    };
+    (@split [$($parsed:expr,)*] [$($current:tt)+]) => {
+        [$($parsed,)* $crate::s!(@axis [] $($current)+)]
+    };
+    (@split [$($parsed:expr,)*] []) => {
+        [$($parsed),*]
+    };
+
+    // one axis: look for a top-level range operator, otherwise it is a single slot
+    (@axis [] ..) => {
+        $crate::array_slice::Axes::FullAxis
+    };
+    (@axis [$($start:tt)*] .. $($rest:tt)*) => {
+        $crate::s!(@step [$($start)*] [false] [] $($rest)*)
+    };
+    (@axis [$($start:tt)*] ..= $($rest:tt)*) => {
+        $crate::s!(@step [$($start)*] [true] [] $($rest)*)
+    };
+    (@axis [$($start:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::s!(@axis [$($start)* $next] $($rest)*)
+    };
+    (@axis [$($slot:tt)+]) => {
+        $crate::s!(@slot $($slot)+)
+    };
+
+    // the stop of a range runs until an optional `;step`
+    (@step [$($start:tt)*] [$inclusive:tt] [$($stop:tt)*] ; $step:expr) => {
+        $crate::s!(@range [$($start)*] [$inclusive] [$($stop)*] $step)
+    };
+    (@step [$($start:tt)*] [$inclusive:tt] [$($stop:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::s!(@step [$($start)*] [$inclusive] [$($stop)* $next] $($rest)*)
+    };
+    (@step [$($start:tt)*] [$inclusive:tt] [$($stop:tt)*]) => {
+        $crate::s!(@range [$($start)*] [$inclusive] [$($stop)*] 1)
+    };
+
+    // omitted bounds cover the axis up to its first or last slot, depending on the sign of the step
+    (@range [$($start:tt)*] [$inclusive:tt] [$($stop:tt)*] $step:expr) => {{
+        let step: isize = $step;
+        $crate::array_slice::Axes::StepRange {
+            start: $crate::s!(@bound [step, Start, End] $($start)*),
+            stop: $crate::s!(@bound [step, End, Start] $($stop)*),
+            step,
+            inclusive: $crate::s!(@inclusive [$inclusive] $($stop)*),
+        }
+    }};
+    (@bound [$step:ident, $forward:ident, $backward:ident]) => {
+        if $step > 0 {
+            $crate::array_slice::Bound::$forward(0)
+        } else {
+            $crate::array_slice::Bound::$backward(0)
+        }
+    };
+    (@bound [$($open:tt)*] end) => {
+        $crate::array_slice::Bound::End(0)
+    };
+    (@bound [$($open:tt)*] end - $k:expr) => {
+        $crate::array_slice::Bound::End($k)
+    };
+    (@bound [$($open:tt)*] $($i:tt)+) => {
+        $crate::array_slice::Bound::Start($($i)+)
+    };
+    (@inclusive [$inclusive:tt]) => {
+        true
+    };
+    (@inclusive [$inclusive:tt] $($stop:tt)+) => {
+        $inclusive
+    };
+
+    (@slot end) => {
+        $crate::array_slice::Axes::SlotFromEnd(0)
+    };
+    (@slot end - $k:expr) => {
+        $crate::array_slice::Axes::SlotFromEnd($k)
+    };
+    (@slot $i:expr) => {
+        $crate::array_slice::Axes::Slot($i)
+    };
+
+    ($($t:tt)*) => {{
+        let slice_info: $crate::s!(@count [] [] [0 0] $($t)*) =
+            $crate::array_slice::SliceInfo::new($crate::s!(@split [] [] $($t)*));
+        slice_info
+    }};
+}
@@ -0,0 +1,360 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_bounds, check_same_shape, check_same_size, ShapeError};
+use crate::array_view::{indices_in_order, ArrayView, ArrayViewMut, AsArrayView};
+use crate::scalar::{AdditiveIdentity, Arithmetic, Field, MultiplicativeIdentity, Ring, Scalar};
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+// ### Stack-allocated Matrices of Fixed Size
+/// Matrix of `R` rows and `C` columns stored inline, i.e., without heap allocation, as julia's `SMatrix{R, C}` of `StaticArrays.jl`
+///
+/// The elements are **column-major** like the default of `Array`: `data[j][i]` is the element `[i, j]`. All loops run over the const extents, so the compiler unrolls them for small sizes, and `determinant` / `inverse` use closed forms up to `4x4`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SArray<T: Scalar, const R: usize, const C: usize> {
+    pub data: [[T; R]; C],
+}
+
+/* Construction and conversion */
+impl<T: Scalar, const R: usize, const C: usize> SArray<T, R, C> {
+    /// Matrix whose element `[i, j]` is `func([i, j])`, filled column by column
+    pub fn from_fn<F: FnMut([usize; 2]) -> T>(mut func: F) -> Self {
+        SArray {
+            data: std::array::from_fn(|j| std::array::from_fn(|i| func([i, j]))),
+        }
+    }
+    /// Matrix with every element zero
+    pub fn zeros() -> Self
+    where
+        T: AdditiveIdentity,
+    {
+        SArray::from_fn(|_| T::ZERO)
+    }
+    /// Borrow the elements as a column-major view, so that every method of `ArrayView` applies
+    pub fn view(&self) -> ArrayView<'_, T, 2> {
+        ArrayView::from_slice(self.data.as_flattened(), [R, C], DataOrder::ColMajor)
+    }
+    /// Borrow the elements as a mutable column-major view
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, 2> {
+        ArrayViewMut::from_slice(self.data.as_flattened_mut(), [R, C], DataOrder::ColMajor)
+    }
+    /// Copy the elements of an array or a view of shape `[R, C]`, whatever its layout
+    pub fn from_view<V: AsArrayView<T, 2>>(src: &V) -> Self {
+        SArray::try_from_view(src).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_view`, returning `ShapeError::ShapeMismatch` if the shape is not `[R, C]`
+    pub fn try_from_view<V: AsArrayView<T, 2>>(src: &V) -> Result<Self, ShapeError> {
+        let src = src.as_view();
+        check_same_shape(&[R, C], &src.shape)?;
+        Ok(SArray::from_fn(|indices| src[indices].clone()))
+    }
+    /// Transpose, by copy
+    pub fn transpose(&self) -> SArray<T, C, R> {
+        SArray::from_fn(|[i, j]| self.data[i][j].clone())
+    }
+}
+
+impl<T: AdditiveIdentity + MultiplicativeIdentity, const N: usize> SArray<T, N, N> {
+    /// Identity matrix
+    pub fn eye() -> Self {
+        SArray::from_fn(|[i, j]| if i == j { T::ONE } else { T::ZERO })
+    }
+}
+
+/// Copy into a column-major `Array`
+impl<T: Scalar, const R: usize, const C: usize> From<SArray<T, R, C>> for Array<T, 2> {
+    fn from(matrix: SArray<T, R, C>) -> Self {
+        Array {
+            data: matrix.data.into_iter().flatten().collect(),
+            shape: [R, C],
+            data_order: DataOrder::ColMajor,
+        }
+    }
+}
+/// Fails if the shape of the array is not `[R, C]`
+impl<T: Scalar, const R: usize, const C: usize> TryFrom<&Array<T, 2>> for SArray<T, R, C> {
+    type Error = ShapeError;
+    fn try_from(array: &Array<T, 2>) -> Result<Self, Self::Error> {
+        SArray::try_from_view(array)
+    }
+}
+impl<T: Scalar, const R: usize, const C: usize> AsArrayView<T, 2> for SArray<T, R, C> {
+    fn as_view(&self) -> ArrayView<'_, T, 2> {
+        self.view()
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Index<[usize; 2]> for SArray<T, R, C> {
+    type Output = T;
+    fn index(&self, [i, j]: [usize; 2]) -> &Self::Output {
+        &self.data[j][i]
+    }
+}
+impl<T: Scalar, const R: usize, const C: usize> IndexMut<[usize; 2]> for SArray<T, R, C> {
+    fn index_mut(&mut self, [i, j]: [usize; 2]) -> &mut Self::Output {
+        &mut self.data[j][i]
+    }
+}
+
+/* Arithmetic */
+/// Matrix multiplication `A*B`, as julia's `*` for matrices
+impl<T: Arithmetic, const R: usize, const K: usize, const C: usize> Mul<SArray<T, K, C>>
+    for SArray<T, R, K>
+{
+    type Output = SArray<T, R, C>;
+    fn mul(self, rhs: SArray<T, K, C>) -> Self::Output {
+        SArray::from_fn(|[i, j]| {
+            (0..K).fold(T::ZERO, |acc, k| {
+                acc + self.data[k][i].clone() * rhs.data[j][k].clone()
+            })
+        })
+    }
+}
+/// Multiplication by a scalar `A*x`
+impl<T: Arithmetic, const R: usize, const C: usize> Mul<T> for SArray<T, R, C> {
+    type Output = SArray<T, R, C>;
+    fn mul(self, rhs: T) -> Self::Output {
+        SArray::from_fn(|[i, j]| self.data[j][i].clone() * rhs.clone())
+    }
+}
+/// Element-wise `A+B`
+impl<T: Scalar + Add<Output = T>, const R: usize, const C: usize> Add for SArray<T, R, C> {
+    type Output = SArray<T, R, C>;
+    fn add(self, rhs: Self) -> Self::Output {
+        SArray::from_fn(|[i, j]| self.data[j][i].clone() + rhs.data[j][i].clone())
+    }
+}
+/// Element-wise `A-B`
+impl<T: Scalar + Sub<Output = T>, const R: usize, const C: usize> Sub for SArray<T, R, C> {
+    type Output = SArray<T, R, C>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        SArray::from_fn(|[i, j]| self.data[j][i].clone() - rhs.data[j][i].clone())
+    }
+}
+impl<T: Scalar + Neg<Output = T>, const R: usize, const C: usize> Neg for SArray<T, R, C> {
+    type Output = SArray<T, R, C>;
+    fn neg(self) -> Self::Output {
+        SArray::from_fn(|[i, j]| -self.data[j][i].clone())
+    }
+}
+
+/* Determinants and inverses in closed form */
+/// Element `[i, j]` of a square matrix, by clone
+macro_rules! at {
+    ($m:expr, $i:literal, $j:literal) => {
+        $m.data[$j][$i].clone()
+    };
+}
+
+impl<T: Ring> SArray<T, 1, 1> {
+    pub fn determinant(&self) -> T {
+        at!(self, 0, 0)
+    }
+}
+impl<T: Field> SArray<T, 1, 1> {
+    /// Inverse, or `None` for a singular matrix
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        (!det.is_zero()).then(|| SArray {
+            data: [[T::ONE / det]],
+        })
+    }
+}
+
+impl<T: Ring> SArray<T, 2, 2> {
+    pub fn determinant(&self) -> T {
+        at!(self, 0, 0) * at!(self, 1, 1) - at!(self, 0, 1) * at!(self, 1, 0)
+    }
+}
+impl<T: Field> SArray<T, 2, 2> {
+    /// Inverse, or `None` for a singular matrix
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        let (a, b, c, d) = (
+            at!(self, 0, 0),
+            at!(self, 0, 1),
+            at!(self, 1, 0),
+            at!(self, 1, 1),
+        );
+        let zero = T::ZERO;
+        Some(SArray {
+            data: [
+                [d / det.clone(), (zero.clone() - c) / det.clone()],
+                [(zero - b) / det.clone(), a / det],
+            ],
+        })
+    }
+}
+
+impl<T: Ring> SArray<T, 3, 3> {
+    /// Cofactor of the element `[i, j]`, taking the rows and columns after `i` and `j` cyclically so that no sign is needed
+    #[inline(always)]
+    fn cofactor(&self, i: usize, j: usize) -> T {
+        let (i1, i2, j1, j2) = ((i + 1) % 3, (i + 2) % 3, (j + 1) % 3, (j + 2) % 3);
+        self.data[j1][i1].clone() * self.data[j2][i2].clone()
+            - self.data[j2][i1].clone() * self.data[j1][i2].clone()
+    }
+    pub fn determinant(&self) -> T {
+        at!(self, 0, 0) * self.cofactor(0, 0)
+            + at!(self, 0, 1) * self.cofactor(0, 1)
+            + at!(self, 0, 2) * self.cofactor(0, 2)
+    }
+}
+impl<T: Field> SArray<T, 3, 3> {
+    /// Inverse, or `None` for a singular matrix, as the adjugate divided by the determinant
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        if det.is_zero() {
+            return None;
+        }
+        Some(SArray::from_fn(|[i, j]| self.cofactor(j, i) / det.clone()))
+    }
+}
+
+impl<T: Ring> SArray<T, 4, 4> {
+    /// The `2x2` minors of the upper two rows and of the lower two rows, from which both the determinant and the adjugate are assembled
+    #[inline(always)]
+    fn minors(&self) -> ([T; 6], [T; 6]) {
+        let minor = |r: usize, a: usize, b: usize| {
+            self.data[a][r].clone() * self.data[b][r + 1].clone()
+                - self.data[a][r + 1].clone() * self.data[b][r].clone()
+        };
+        (
+            [
+                minor(0, 0, 1),
+                minor(0, 0, 2),
+                minor(0, 0, 3),
+                minor(0, 1, 2),
+                minor(0, 1, 3),
+                minor(0, 2, 3),
+            ],
+            [
+                minor(2, 0, 1),
+                minor(2, 0, 2),
+                minor(2, 0, 3),
+                minor(2, 1, 2),
+                minor(2, 1, 3),
+                minor(2, 2, 3),
+            ],
+        )
+    }
+    pub fn determinant(&self) -> T {
+        let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = self.minors();
+        s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0
+    }
+}
+impl<T: Field> SArray<T, 4, 4> {
+    /// Inverse, or `None` for a singular matrix, by the Laplace expansion along the upper and lower pairs of rows
+    pub fn inverse(&self) -> Option<Self> {
+        let ([s0, s1, s2, s3, s4, s5], [c0, c1, c2, c3, c4, c5]) = self.minors();
+        let det = s0.clone() * c5.clone() - s1.clone() * c4.clone()
+            + s2.clone() * c3.clone()
+            + s3.clone() * c2.clone()
+            - s4.clone() * c1.clone()
+            + s5.clone() * c0.clone();
+        if det.is_zero() {
+            return None;
+        }
+        let a = |i: usize, j: usize| self.data[j][i].clone();
+        // adjugate with rows `i` and columns `j`, i.e., `adj[i][j]` is the cofactor of `[j, i]`
+        let adjugate = [
+            [
+                a(1, 1) * c5.clone() - a(1, 2) * c4.clone() + a(1, 3) * c3.clone(),
+                a(0, 2) * c4.clone() - a(0, 1) * c5.clone() - a(0, 3) * c3.clone(),
+                a(3, 1) * s5.clone() - a(3, 2) * s4.clone() + a(3, 3) * s3.clone(),
+                a(2, 2) * s4.clone() - a(2, 1) * s5.clone() - a(2, 3) * s3.clone(),
+            ],
+            [
+                a(1, 2) * c2.clone() - a(1, 0) * c5.clone() - a(1, 3) * c1.clone(),
+                a(0, 0) * c5 - a(0, 2) * c2.clone() + a(0, 3) * c1.clone(),
+                a(3, 2) * s2.clone() - a(3, 0) * s5.clone() - a(3, 3) * s1.clone(),
+                a(2, 0) * s5 - a(2, 2) * s2.clone() + a(2, 3) * s1.clone(),
+            ],
+            [
+                a(1, 0) * c4.clone() - a(1, 1) * c2.clone() + a(1, 3) * c0.clone(),
+                a(0, 1) * c2 - a(0, 0) * c4 - a(0, 3) * c0.clone(),
+                a(3, 0) * s4.clone() - a(3, 1) * s2.clone() + a(3, 3) * s0.clone(),
+                a(2, 1) * s2 - a(2, 0) * s4 - a(2, 3) * s0.clone(),
+            ],
+            [
+                a(1, 1) * c1.clone() - a(1, 0) * c3.clone() - a(1, 2) * c0.clone(),
+                a(0, 0) * c3 - a(0, 1) * c1 + a(0, 2) * c0,
+                a(3, 1) * s1.clone() - a(3, 0) * s3.clone() - a(3, 2) * s0.clone(),
+                a(2, 0) * s3 - a(2, 1) * s1 + a(2, 2) * s0,
+            ],
+        ];
+        Some(SArray::from_fn(|[i, j]| {
+            adjugate[i][j].clone() / det.clone()
+        }))
+    }
+}
+
+// ### Stack-allocated Arrays of Any Dimension
+/// Inline counterpart of `Array<T, D>` holding exactly `N` elements, e.g., `SArrayN<f64, 27, 3>` for a `3x3x3` tensor
+///
+/// The shape is checked against `N` on construction, and the elements are column-major.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SArrayN<T: Scalar, const N: usize, const D: usize> {
+    pub data: [T; N],
+    pub shape: [usize; D],
+}
+
+impl<T: Scalar, const N: usize, const D: usize> SArrayN<T, N, D> {
+    /// Array whose element `[i,j,k,...]` is `func([i,j,k,...])`
+    pub fn from_fn<F: FnMut([usize; D]) -> T>(shape: [usize; D], func: F) -> Self {
+        SArrayN::try_from_fn(shape, func).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_fn`, returning `ShapeError::SizeMismatch` if `shape` does not hold exactly `N` elements
+    pub fn try_from_fn<F: FnMut([usize; D]) -> T>(
+        shape: [usize; D],
+        func: F,
+    ) -> Result<Self, ShapeError> {
+        check_same_size(&[N], &shape)?;
+        let mut indices = indices_in_order(shape, DataOrder::ColMajor).map(func);
+        Ok(SArrayN {
+            data: std::array::from_fn(|_| indices.next().expect("the shape holds `N` elements")),
+            shape,
+        })
+    }
+    /// Copy the elements of an array or a view, whatever its layout
+    pub fn from_view<V: AsArrayView<T, D>>(src: &V) -> Self {
+        SArrayN::try_from_view(src).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_view`, returning `ShapeError::SizeMismatch` if the source does not hold exactly `N` elements
+    pub fn try_from_view<V: AsArrayView<T, D>>(src: &V) -> Result<Self, ShapeError> {
+        let src = src.as_view();
+        SArrayN::try_from_fn(src.shape, |indices| src[indices].clone())
+    }
+    /// Borrow the elements as a column-major view
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView::from_slice(&self.data, self.shape, DataOrder::ColMajor)
+    }
+    /// Borrow the elements as a mutable column-major view
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        ArrayViewMut::from_slice(&mut self.data, self.shape, DataOrder::ColMajor)
+    }
+}
+
+/// Copy into a column-major `Array`
+impl<T: Scalar, const N: usize, const D: usize> From<SArrayN<T, N, D>> for Array<T, D> {
+    fn from(array: SArrayN<T, N, D>) -> Self {
+        Array {
+            data: array.data.into(),
+            shape: array.shape,
+            data_order: DataOrder::ColMajor,
+        }
+    }
+}
+impl<T: Scalar, const N: usize, const D: usize> AsArrayView<T, D> for SArrayN<T, N, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+impl<T: Scalar, const N: usize, const D: usize> Index<[usize; D]> for SArrayN<T, N, D> {
+    type Output = T;
+    fn index(&self, indices: [usize; D]) -> &Self::Output {
+        check_bounds(&self.shape, &indices).unwrap_or_else(|error| panic!("{error}"));
+        &self.data[self.view().data_index(&indices)]
+    }
+}
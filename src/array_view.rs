@@ -0,0 +1,380 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_same_shape, ShapeError};
+use crate::scalar::Scalar;
+use num_complex::Complex;
+use std::alloc::Allocator;
+
+// ### Borrowed Strided Views into `Array<T, D>`
+/// A read-only view borrows the buffer of its parent and describes the viewed elements by `shape`, per-axis `strides` and an `offset`.
+///
+/// The element at position `[i,j,k,...]` lives at `data[offset + i * strides[0] + j * strides[1] + k * strides[2] + ...]`, so sub-blocks, transposes and stepped slices of the parent can all be expressed **without copying**. Strides are signed to allow reversed axes.
+pub struct ArrayView<'a, T: Scalar, const D: usize> {
+    pub data: &'a [T],
+    pub shape: [usize; D],
+    pub strides: [isize; D],
+    pub offset: usize,
+}
+// manual impls: `#[derive]` would require `T: Copy`, while copying a view only copies the borrow
+impl<'a, T: Scalar, const D: usize> Clone for ArrayView<'a, T, D> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<'a, T: Scalar, const D: usize> Copy for ArrayView<'a, T, D> {}
+
+/// Mutable counterpart of `ArrayView`: writes go straight into the buffer of the parent
+pub struct ArrayViewMut<'a, T: Scalar, const D: usize> {
+    pub data: &'a mut [T],
+    pub shape: [usize; D],
+    pub strides: [isize; D],
+    pub offset: usize,
+}
+
+/// Anything that can be borrowed as an `ArrayView`, i.e., owned arrays and views alike.
+///
+/// Read-only methods take their array operands as `&impl AsArrayView<T, D>` so that both `&Array` and `&ArrayView` are accepted.
+pub trait AsArrayView<T: Scalar, const D: usize> {
+    fn as_view(&self) -> ArrayView<'_, T, D>;
+}
+impl<T: Scalar, const D: usize, A: Allocator> AsArrayView<T, D> for Array<T, D, A> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+impl<'a, T: Scalar, const D: usize> AsArrayView<T, D> for ArrayView<'a, T, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        *self
+    }
+}
+impl<'a, T: Scalar, const D: usize> AsArrayView<T, D> for ArrayViewMut<'a, T, D> {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        self.view()
+    }
+}
+// a single scalar of a built-in type acts as a zero-dimensional view, which broadcasts to any shape (a generic `impl for T` would overlap with the one for `&V`)
+macro_rules! impl_AsArrayView_for_scalar_types {
+    ($($type:ty) *) => ($(
+        impl AsArrayView<$type, 0> for $type {
+            fn as_view(&self) -> ArrayView<'_, $type, 0> {
+                ArrayView {
+                    data: std::slice::from_ref(self),
+                    shape: [],
+                    strides: [],
+                    offset: 0,
+                }
+            }
+        }
+    )*)
+}
+impl_AsArrayView_for_scalar_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 bool Complex<f32> Complex<f64>);
+impl<T: Scalar, V: AsArrayView<T, D>, const D: usize> AsArrayView<T, D> for &V {
+    fn as_view(&self) -> ArrayView<'_, T, D> {
+        (**self).as_view()
+    }
+}
+
+/// Iterator over all multi-indices `[i,j,k,...]` of the given shape in **column-major** order (the first index runs fastest)
+pub(crate) struct ColMajorIndices<const D: usize> {
+    shape: [usize; D],
+    current: Option<[usize; D]>,
+}
+impl<const D: usize> ColMajorIndices<D> {
+    pub(crate) fn new(shape: [usize; D]) -> Self {
+        let current = if shape.contains(&0) {
+            None
+        } else {
+            Some([0; D])
+        };
+        ColMajorIndices { shape, current }
+    }
+}
+impl<const D: usize> Iterator for ColMajorIndices<D> {
+    type Item = [usize; D];
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.current?;
+        let mut next = indices;
+        self.current = None;
+        for axis in 0..D {
+            next[axis] += 1;
+            if next[axis] < self.shape[axis] {
+                self.current = Some(next);
+                break;
+            }
+            next[axis] = 0;
+        }
+        Some(indices)
+    }
+}
+
+/// All multi-indices of `shape` in the storage order of `order`
+pub(crate) fn indices_in_order<const D: usize>(
+    shape: [usize; D],
+    order: DataOrder,
+) -> impl Iterator<Item = [usize; D]> {
+    let reversed = order == DataOrder::RowMajor;
+    let mut iteration_shape = shape;
+    if reversed {
+        iteration_shape.reverse(); // row-major order is column-major order of the reversed axes
+    }
+    ColMajorIndices::new(iteration_shape).map(move |mut indices| {
+        if reversed {
+            indices.reverse();
+        }
+        indices
+    })
+}
+
+/// Range `start..end` of the buffer positions covered by a non-empty strided layout
+pub(crate) fn span<const D: usize>(
+    offset: usize,
+    shape: &[usize; D],
+    strides: &[isize; D],
+) -> (usize, usize) {
+    let (mut start, mut end) = (offset as isize, offset as isize);
+    for (&n, &s) in shape.iter().zip(strides.iter()) {
+        let reach = (n.saturating_sub(1)) as isize * s;
+        if reach < 0 {
+            start += reach;
+        } else {
+            end += reach;
+        }
+    }
+    (start as usize, end as usize + 1)
+}
+
+/// Position of `indices` in a strided buffer
+#[inline]
+pub(crate) fn strided_data_index<const D: usize>(
+    offset: usize,
+    strides: &[isize; D],
+    indices: &[usize; D],
+) -> usize {
+    let position = indices
+        .iter()
+        .zip(strides.iter())
+        .fold(offset as isize, |acc, (&i, &s)| acc + i as isize * s);
+    position as usize
+}
+
+/* Construction of views from owned arrays */
+impl<T: Scalar, const D: usize, A: Allocator> Array<T, D, A> {
+    /// Per-axis strides of the underlying buffer implied by `data_order`
+    pub fn strides(&self) -> [isize; D] {
+        self.data_order.strides(&self.shape)
+    }
+    /// Borrow the whole array as a read-only view
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView {
+            data: &self.data,
+            shape: self.shape,
+            strides: self.strides(),
+            offset: 0,
+        }
+    }
+    /// Borrow the whole array as a mutable view
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        let strides = self.strides();
+        ArrayViewMut {
+            data: &mut self.data,
+            shape: self.shape,
+            strides,
+            offset: 0,
+        }
+    }
+}
+
+/* Method implementations for read-only views */
+impl<'a, T: Scalar, const D: usize> ArrayView<'a, T, D> {
+    /// Number of elements in the view
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Position of the element `[i,j,k,...]` in the borrowed buffer
+    #[inline]
+    pub fn data_index(&self, indices: &[usize; D]) -> usize {
+        strided_data_index(self.offset, &self.strides, indices)
+    }
+    /// The layout in which the viewed elements are packed without gaps, if any
+    pub fn contiguous_order(&self) -> Option<DataOrder> {
+        [DataOrder::ColMajor, DataOrder::RowMajor]
+            .into_iter()
+            .find(|&order| self.is_contiguous_in(order))
+    }
+    /// The viewed elements as one slice in storage order, available when the view is contiguous
+    pub fn as_slice(&self) -> Option<&'a [T]> {
+        self.contiguous_order()
+            .map(|_| &self.data[self.offset..self.offset + self.len()])
+    }
+    /// Iterate the elements in **column-major** logical order
+    pub(crate) fn elements(&self) -> impl Iterator<Item = &'a T> + 'a {
+        let view = *self;
+        ColMajorIndices::new(self.shape).map(move |indices| &view.data[view.data_index(&indices)])
+    }
+    /// Copy the viewed elements into a new owned array, keeping the layout when the view is contiguous
+    pub fn to_owned(self) -> Array<T, D> {
+        self.map(|x| x)
+    }
+    /// Element-wisely Broadcast with closures into a new owned array
+    ///
+    /// Contiguous views keep their `data_order`; strided views are collected in column-major order.
+    pub fn map<U: Scalar, F: Fn(T) -> U>(&self, func: F) -> Array<U, D> {
+        match (self.as_slice(), self.contiguous_order()) {
+            (Some(slice), Some(data_order)) => Array {
+                data: slice.iter().cloned().map(func).collect(),
+                shape: self.shape,
+                data_order,
+            },
+            _ => Array {
+                data: self.elements().cloned().map(func).collect(),
+                shape: self.shape,
+                data_order: DataOrder::ColMajor,
+            },
+        }
+    }
+    /// Combine two views of equal shape element by element into a new owned array
+    pub fn zip_with<U: Scalar, V: Scalar, F: Fn(T, U) -> V>(
+        &self,
+        rhs: &ArrayView<'_, U, D>,
+        func: F,
+    ) -> Array<V, D> {
+        self.try_zip_with(rhs, func)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `zip_with`, returning `ShapeError::ShapeMismatch` for views of different shapes
+    pub fn try_zip_with<U: Scalar, V: Scalar, F: Fn(T, U) -> V>(
+        &self,
+        rhs: &ArrayView<'_, U, D>,
+        func: F,
+    ) -> Result<Array<V, D>, ShapeError> {
+        check_same_shape(&self.shape, &rhs.shape)?;
+        Ok(match (self.as_slice(), rhs.as_slice()) {
+            // fast path: both operands are packed in the same layout
+            (Some(lhs_slice), Some(rhs_slice))
+                if self.contiguous_order() == rhs.contiguous_order() =>
+            {
+                Array {
+                    data: lhs_slice
+                        .iter()
+                        .zip(rhs_slice.iter())
+                        .map(|(a, b)| func(a.clone(), b.clone()))
+                        .collect(),
+                    shape: self.shape,
+                    data_order: self.contiguous_order().unwrap(),
+                }
+            }
+            _ => Array {
+                data: self
+                    .elements()
+                    .zip(rhs.elements())
+                    .map(|(a, b)| func(a.clone(), b.clone()))
+                    .collect(),
+                shape: self.shape,
+                data_order: DataOrder::ColMajor,
+            },
+        })
+    }
+    /// Get the maximum string length for the element: also work for future symbolic variables!
+    pub fn get_element_length_and_interval(
+        &self,
+        decimal_length: usize,
+        element_interval: usize,
+    ) -> usize {
+        let width = self
+            .elements()
+            .map(|x| x.display_string(decimal_length).len())
+            .max()
+            .unwrap_or(0);
+
+        width + element_interval
+    }
+}
+
+/* Method implementations for mutable views */
+impl<'a, T: Scalar, const D: usize> ArrayViewMut<'a, T, D> {
+    /// Reborrow as a read-only view
+    pub fn view(&self) -> ArrayView<'_, T, D> {
+        ArrayView {
+            data: &*self.data,
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+    /// Reborrow as a shorter-lived mutable view
+    pub fn view_mut(&mut self) -> ArrayViewMut<'_, T, D> {
+        ArrayViewMut {
+            data: &mut *self.data,
+            shape: self.shape,
+            strides: self.strides,
+            offset: self.offset,
+        }
+    }
+    /// Position of the element `[i,j,k,...]` in the borrowed buffer
+    #[inline]
+    pub fn data_index(&self, indices: &[usize; D]) -> usize {
+        strided_data_index(self.offset, &self.strides, indices)
+    }
+    /// Set every viewed element to `value`
+    pub fn fill(&mut self, value: T) {
+        for indices in ColMajorIndices::new(self.shape) {
+            let data_index = self.data_index(&indices);
+            self.data[data_index] = value.clone();
+        }
+    }
+    /// Update every viewed element with the corresponding element of `rhs`, which is broadcast to the shape of the view
+    ///
+    /// Nothing is written if `rhs` cannot be broadcast, in which case the error is returned.
+    pub fn zip_assign_with<U: Scalar, F: Fn(&mut T, U), const E: usize>(
+        &mut self,
+        rhs: &ArrayView<'_, U, E>,
+        func: F,
+    ) -> Result<(), ShapeError> {
+        let rhs = rhs.broadcast_to(self.shape)?;
+        let lhs_order = self.view().contiguous_order();
+        match (lhs_order, rhs.as_slice()) {
+            // fast path: both sides are packed in the same layout
+            (Some(order), Some(rhs_slice)) if rhs.is_contiguous_in(order) => {
+                let len = rhs_slice.len();
+                for (a, b) in self.data[self.offset..self.offset + len]
+                    .iter_mut()
+                    .zip(rhs_slice.iter())
+                {
+                    func(a, b.clone());
+                }
+            }
+            _ => {
+                for indices in ColMajorIndices::new(self.shape) {
+                    let data_index = self.data_index(&indices);
+                    func(&mut self.data[data_index], rhs[indices].clone());
+                }
+            }
+        }
+        Ok(())
+    }
+    /// Copy the elements of `src` (of equal shape) into the viewed elements
+    pub fn assign<V: AsArrayView<T, D>>(&mut self, src: &V) {
+        self.try_assign(src)
+            .unwrap_or_else(|error| panic!("{error}"));
+    }
+    /// Fallible `assign`, returning `ShapeError::ShapeMismatch` (and writing nothing) if the shapes differ
+    pub fn try_assign<V: AsArrayView<T, D>>(&mut self, src: &V) -> Result<(), ShapeError> {
+        let src = src.as_view();
+        check_same_shape(&self.shape, &src.shape)?;
+        for indices in ColMajorIndices::new(self.shape) {
+            let data_index = self.data_index(&indices);
+            self.data[data_index] = src.data[src.data_index(&indices)].clone();
+        }
+        Ok(())
+    }
+}
+
+/* Implementations for two-dimensional views */
+impl<'a, T: Scalar> ArrayView<'a, T, 2> {
+    /// Zero-copy transpose: swap the shape and strides of the two axes, i.e., `permutedims_view([1, 0])`
+    pub fn transpose(&self) -> ArrayView<'a, T, 2> {
+        self.permutedims_view([1, 0])
+    }
+}
@@ -1,12 +1,30 @@
 #![feature(portable_simd)]
+#![feature(allocator_api)]
 
 use rs_bench::*;
 
+mod array_aligned;
 mod array_basic;
+mod array_broadcast;
+mod array_complex;
+mod array_concat;
 mod array_display;
+mod array_dyn;
+mod array_error;
+mod array_index;
+mod array_iter;
+mod array_layout;
+mod array_mask;
+mod array_new;
+mod array_ops;
+mod array_random;
+mod array_reduce;
+mod array_shared;
+mod array_slice;
+mod array_static;
+mod array_view;
 mod matrix;
-// mod matrix_simd;
-// mod array_slice;
+mod matrix_sparse;
 mod scalar;
 
 // #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
@@ -47,6 +65,1015 @@ mod tests {
         // println!("{}", m.mul_naive(&m.transpose()));
     }
 
+    #[test]
+    fn view_test() {
+        let m = matrix![1 2 3; 4 5 6];
+        let mt = m.transpose_view(); // no copy of the data
+        assert_eq!(mt.shape, [3, 2]);
+        assert_eq!(mt.data[mt.data_index(&[2, 1])], 6);
+        // the display of a view shows the same grid as the equivalent owned array
+        let printed = format!("{mt}");
+        assert!(printed.contains("ArrayView<i32, [3, 2]>"));
+        let grid = |s: String| s.lines().skip(2).collect::<Vec<_>>().join("\n");
+        assert_eq!(grid(printed), grid(format!("{}", m.transpose())));
+
+        // views and owned arrays can be mixed freely in read-only operations
+        let mt_owned = m.transpose();
+        assert_eq!((mt + &mt_owned).data, vec![2, 4, 6, 8, 10, 12]);
+        assert_eq!(mt.map(|x| x * 10).data, vec![10, 20, 30, 40, 50, 60]);
+
+        let p = m.mul_naive(&mt);
+        assert_eq!(p.shape, [2, 2]);
+        assert_eq!(p.data, vec![14, 32, 32, 77]);
+
+        let mut m = m;
+        m.view_mut().fill(0);
+        assert!(m.data.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn slice_test() {
+        use crate::array_basic::*;
+        use crate::array_view::ArrayView;
+
+        let m = matrix![1 2 3 4; 5 6 7 8; 9 10 11 12]; // row-major storage
+        let block: ArrayView<_, 2> = m.slice_view(&s![1..3, 1..=2]);
+        assert_eq!(block.shape, [2, 2]);
+        assert_eq!(block.to_owned().data, vec![6, 10, 7, 11]);
+
+        let row: ArrayView<_, 1> = m.slice_view(&s![end, ..;2]);
+        assert_eq!(row.to_owned().data, vec![9, 11]);
+        let col: ArrayView<_, 1> = m.slice_view(&s![..;-1, end - 1]);
+        assert_eq!(col.to_owned().data, vec![11, 7, 3]);
+
+        // the same slices on column-major storage
+        let m_col = Array {
+            data: vec![1, 5, 9, 2, 6, 10, 3, 7, 11, 4, 8, 12],
+            shape: [3, 4],
+            data_order: DataOrder::ColMajor,
+        };
+        let block: Array<_, 2> = m_col.slice(&s![1..3, 1..=2]);
+        assert_eq!(block.data, vec![6, 10, 7, 11]);
+
+        let mut m = m;
+        let mut col = m.slice_view_mut::<1>(&s![.., 0]);
+        col.fill(0);
+        assert_eq!(m.slice::<1>(&s![.., 0]).data, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn slice_rank_reduction_test() {
+        use crate::array_basic::*;
+
+        let m = Array {
+            data: (0..24).collect(),
+            shape: [2, 3, 4],
+            data_order: DataOrder::ColMajor,
+        };
+        // the dimension of the result follows from the slice spec: no annotation and no reshape needed
+        let front = m.slice(&s![0, .., ..]);
+        assert_eq!(front.shape, [3, 4]);
+        assert_eq!(front.transpose().shape, [4, 3]);
+        let back = m.slice_view(&s![end, .., ..]);
+        assert_eq!(front.mul_naive(&back.transpose()).shape, [3, 3]);
+        let fiber = m.slice_view(&s![1, end - 1, ..]);
+        assert_eq!(fiber.to_owned().data, vec![3, 9, 15, 21]);
+    }
+
+    #[test]
+    fn index_test() {
+        let mut m = matrix![1 2 3; 4 5 6]; // row-major storage
+        assert_eq!(m[[0, 2]], 3);
+        assert_eq!(m[[1, 0]], 4);
+        assert_eq!(calculate_data_index!(m, [1, 0]), 3);
+        assert_eq!(m.get([2, 0]), None);
+
+        let mt = m.transpose(); // column-major storage of the same elements
+        assert_eq!(mt[[2, 0]], 3);
+        assert_eq!(mt.transpose_view()[[1, 2]], 6);
+
+        m[[1, 1]] = 50;
+        *m.get_mut([0, 0]).unwrap() = 10;
+        assert_eq!(m.data, vec![10, 2, 3, 4, 50, 6]);
+        let mut row = m.slice_view_mut(&s![1, ..]);
+        row[[2]] = 60;
+        assert_eq!(m[[1, 2]], 60);
+    }
+
+    #[test]
+    fn dynamic_dimension_test() {
+        use crate::array_basic::{Array, DataOrder};
+        use crate::array_dyn::ArrayD;
+        use crate::array_error::ShapeError;
+
+        let m = matrix![1 2 3; 4 5 6];
+        let m3 = m.reshape([1, 2, 3]); // 2-D to 3-D
+        assert_eq!(m3[[0, 1, 2]], 6);
+
+        let d = ArrayD::from(m.clone());
+        assert_eq!(d.ndim(), 2);
+        assert_eq!(d[&[1, 0]], 4);
+        let d3 = d.reshape(&[3, 1, 2]);
+        assert_eq!(d3.ndim(), 3);
+        let printed = format!("{d3}");
+        assert!(printed.contains("ArrayD<i32, [3, 1, 2]>"));
+        let grid = |s: String| s.lines().skip(2).collect::<Vec<_>>().join("\n");
+        assert_eq!(grid(printed), grid(format!("{}", m.reshape([3, 1, 2]))));
+
+        let sum = &d + &m.transpose().transpose().into_dyn();
+        assert_eq!(sum.get(&[1, 2]), Some(&12));
+        assert!(Array::<i32, 3>::try_from(sum.clone()).is_err());
+        let back: Array<i32, 2> = sum.into_fixed().unwrap();
+        assert_eq!(back[[0, 1]], 4);
+
+        // element-wise operators, scalars on the right, layouts and whole-array reductions
+        let c = d3.to_order(DataOrder::ColMajor);
+        assert_eq!(c.data, vec![1, 3, 5, 2, 4, 6]);
+        assert_eq!(c[&[2, 0, 1]], d3[&[2, 0, 1]]);
+        assert_eq!(c.clone().into_order(DataOrder::RowMajor).data, d3.data);
+        let product = &d3 * &c; // visited in column-major order, as the layouts differ
+        assert_eq!(product.data, vec![1, 9, 25, 4, 16, 36]);
+        assert_eq!((-(product / 2) % 5).data, vec![0, -4, -2, -2, -3, -3]);
+        assert_eq!((&d3 - 1).sum(), 15);
+        assert_eq!(d3.prod(), 720);
+        assert_eq!(
+            (d3.minimum(), c.into_order(DataOrder::RowMajor).maximum()),
+            (Some(1), Some(6))
+        );
+        assert_eq!(d3.map(|x| x as f64).mean(), 3.5);
+        assert!(matches!(
+            d3.try_div(&d),
+            Err(ShapeError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn layout_conversion_test() {
+        use crate::array_basic::*;
+
+        let m = Array {
+            data: (0..70 * 45).collect::<Vec<i64>>(),
+            shape: [70, 45], // spans several tiles of the blocked kernel
+            data_order: DataOrder::RowMajor,
+        };
+        assert!(m.is_contiguous_in(DataOrder::RowMajor));
+        assert!(!m.is_contiguous_in(DataOrder::ColMajor));
+
+        let c = m.to_order(DataOrder::ColMajor);
+        assert!(c.is_contiguous_in(DataOrder::ColMajor));
+        assert_eq!(c.data[1], 45); // element [1, 0] follows [0, 0] in column-major storage
+        assert_eq!(c[[69, 44]], m[[69, 44]]);
+        assert_eq!(c[[12, 34]], m[[12, 34]]);
+        let r = c.clone().into_order(DataOrder::RowMajor);
+        assert_eq!(r.data, m.data);
+
+        // higher dimensions and strided views
+        let t = Array {
+            data: (0..24).collect::<Vec<i64>>(),
+            shape: [2, 3, 4],
+            data_order: DataOrder::RowMajor,
+        };
+        let tc = t.to_order(DataOrder::ColMajor);
+        assert_eq!(tc[[1, 2, 3]], 23);
+        assert_eq!(tc.data[..3], [0, 12, 4]);
+        let sub = t
+            .slice_view(&s![.., 1.., ..;2])
+            .to_order(DataOrder::RowMajor);
+        assert_eq!(sub.data, vec![4, 6, 8, 10, 16, 18, 20, 22]);
+
+        // matrix multiplication gives the same result whatever the layout of its operands
+        let a = matrix![1 2 3; 4 5 6];
+        let b = a.transpose();
+        let expected = vec![14, 32, 32, 77];
+        assert_eq!(a.mul_naive(&b).data, expected);
+        assert_eq!(
+            a.to_order(DataOrder::ColMajor)
+                .mul_naive(&b.to_order(DataOrder::RowMajor))
+                .data,
+            expected
+        );
+    }
+
+    #[test]
+    fn broadcast_test() {
+        use crate::array_basic::*;
+        use crate::array_broadcast::broadcast_shape;
+        use crate::array_error::ShapeError;
+
+        let m = matrix![1 2 3; 4 5 6];
+        let col = Array {
+            data: vec![10, 20],
+            shape: [2],
+            data_order: DataOrder::ColMajor,
+        };
+        // a vector is a column: it is added to each column of the matrix
+        let res = &m + &col;
+        assert_eq!(res.shape, [2, 3]);
+        assert_eq!(res[[1, 2]], 26);
+        assert_eq!((&col - &m)[[0, 1]], 8);
+
+        let row = matrix![100 200 300]; // shape [1, 3] stretches along the first axis
+        assert_eq!((m.clone() + row)[[1, 0]], 104);
+
+        assert_eq!(broadcast_shape(&[4, 1, 3], &[1, 5]), Ok([4, 5, 3]));
+        let error = broadcast_shape::<2, 1, 2>(&[2, 3], &[3]).unwrap_err();
+        assert!(matches!(
+            error,
+            ShapeError::IncompatibleShapes { axis: 0, .. }
+        ));
+        assert!(error.to_string().contains("[2, 3] and [3]"));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be broadcast together")]
+    fn broadcast_mismatch_test() {
+        let _ = &matrix![1 2 3; 4 5 6] + &matrix![1 2; 3 4];
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of bounds for axis 1 with extent 3")]
+    fn index_out_of_bounds_test() {
+        let m = matrix![1 2 3; 4 5 6];
+        let _ = m[[0, 3]];
+    }
+
+    #[test]
+    #[should_panic(expected = "must reach distinct elements")]
+    fn aliasing_iter_mut_test() {
+        use crate::array_view::ArrayViewMut;
+        let mut data = vec![1, 2, 3];
+        // a broadcast stride of zero would hand out the same element three times
+        let mut view = ArrayViewMut {
+            data: &mut data,
+            shape: [3],
+            strides: [0],
+            offset: 0,
+        };
+        view.iter_mut().for_each(|x| *x += 1);
+    }
+
+    #[test]
+    fn operators_test() {
+        use crate::array_basic::*;
+        let a = matrix![1 2 3; 4 5 6];
+        let b = matrix![6 5 4; 3 2 1];
+        assert_eq!(
+            (&a * &b).to_order(DataOrder::RowMajor).data,
+            vec![6, 10, 12, 12, 10, 6]
+        );
+        assert_eq!((&b / &a).data, vec![6, 2, 1, 0, 0, 0]);
+        assert_eq!((&b % &a).data, vec![0, 1, 1, 3, 2, 1]);
+        assert_eq!((-&a).data, vec![-1, -2, -3, -4, -5, -6]);
+        // scalar operands on either side
+        assert_eq!((&a * 2).data, vec![2, 4, 6, 8, 10, 12]);
+        assert_eq!((10i32 - a.clone()).data, vec![9, 8, 7, 6, 5, 4]);
+        // owned operands reuse the buffer of the broadcast-compatible side
+        let column = Array {
+            data: vec![10, 20],
+            shape: [2],
+            data_order: DataOrder::ColMajor,
+        };
+        let sum = a.clone() + &column;
+        assert_eq!(sum.shape, [2, 3]);
+        assert_eq!(sum[[1, 2]], 26);
+        let sum = &column + a.clone();
+        assert_eq!(sum[[0, 1]], 12);
+        // compound assignment on arrays and views
+        let mut c = a.clone();
+        c += &b;
+        c *= 2;
+        c -= column.view();
+        assert_eq!(c[[0, 0]], 4);
+        assert_eq!(c[[1, 2]], -6);
+        let mut d = a.clone();
+        let mut first_row = d.slice_view_mut(&s![0, ..]);
+        first_row *= 10;
+        assert_eq!(d.data, vec![10, 20, 30, 4, 5, 6]);
+    }
+
+    #[test]
+    fn shape_error_test() {
+        use crate::array_basic::*;
+        use crate::array_dyn::ArrayD;
+        use crate::array_error::ShapeError;
+        let m = matrix![1 2 3; 4 5 6];
+        assert_eq!(
+            m.try_reshape([4, 2]).unwrap_err(),
+            ShapeError::SizeMismatch {
+                shape: vec![2, 3],
+                new_shape: vec![4, 2]
+            }
+        );
+        assert_eq!(m.try_reshape([3, 2]).unwrap().shape, [3, 2]);
+
+        let sum: Result<Array<i32, 2>, _> = m.try_add(&matrix![1 2; 3 4]);
+        assert!(matches!(
+            sum,
+            Err(ShapeError::IncompatibleShapes { axis: 1, .. })
+        ));
+        let product: Array<i32, 2> = m.try_mul(&m.view()).unwrap();
+        assert_eq!(product[[1, 2]], 36);
+
+        let error = m.try_matmul(&m).unwrap_err();
+        assert_eq!(
+            error,
+            ShapeError::InnerDimensionMismatch {
+                lhs: vec![2, 3],
+                rhs: vec![2, 3]
+            }
+        );
+        assert!(error.to_string().contains("inner extents 3 and 2"));
+        assert_eq!(m.try_matmul(&m.transpose_view()).unwrap()[[1, 1]], 77);
+
+        assert!(matches!(
+            m.try_data_index(&[2, 0]),
+            Err(ShapeError::IndexOutOfBounds { axis: 0, .. })
+        ));
+        let d = m.clone().into_dyn();
+        assert_eq!(
+            d.try_data_index(&[0, 0, 0]),
+            Err(ShapeError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        );
+        let e: ArrayD<i32> = d.reshape(&[6]);
+        assert!(matches!(
+            d.try_add(&e),
+            Err(ShapeError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn iterator_test() {
+        use crate::array_basic::*;
+        use crate::array_view::ArrayView;
+        let mut m = matrix![1 2 3; 4 5 6]; // row-major storage
+        assert_eq!(
+            m.iter().copied().collect::<Vec<_>>(),
+            vec![1, 4, 2, 5, 3, 6]
+        );
+        assert_eq!(m.indexed_iter().nth(3), Some(([1, 1], &5)));
+        for (k, x) in m.iter_mut().enumerate() {
+            *x = k as i32;
+        }
+        assert_eq!(m[[0, 1]], 2);
+        let mut t = m.slice_view_mut(&s![..;-1, 1..]);
+        for x in t.iter_mut() {
+            *x *= 10;
+        }
+        assert_eq!(m.data, vec![0, 20, 40, 1, 30, 50]);
+        // a strided column of a large matrix is walked in place, without collecting the buffer it spans
+        let mut big = Array::from_fn([500, 400], DataOrder::RowMajor, |[i, j]| (i * j) as i64);
+        let mut col = big.slice_view_mut(&s![1..;2, 3]);
+        col.iter_mut().for_each(|x| *x = -*x);
+        assert_eq!(col.iter_mut().count(), 250);
+        assert_eq!((big[[1, 3]], big[[2, 3]], big[[499, 3]]), (-3, 6, -1497));
+        let mut cube = Array::from_fn([3, 4, 5], DataOrder::ColMajor, |[i, j, k]| i + j + k);
+        let mut corner = cube.slice_view_mut(&s![..;2, 1..;-1, end]);
+        corner.iter_mut().for_each(|x| *x = 0);
+        let corner = cube.slice(&s![..;2, 1..;-1, end]);
+        assert!(corner.shape.iter().product::<usize>() > 1 && corner.iter().all(|&x| x == 0));
+        assert_eq!(
+            cube.iter().filter(|&&x| x == 0).count(),
+            corner.data.len() + 1
+        );
+
+        let m = matrix![1 2 3; 4 5 6];
+        let rows: Vec<Vec<i32>> = m.rows().map(|r| r.iter().copied().collect()).collect();
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let cols: Vec<i32> = m.cols().map(|c| c.iter().sum()).collect();
+        assert_eq!(cols, vec![5, 7, 9]);
+
+        let a = m.reshape([2, 1, 3]);
+        assert_eq!(a.lanes(2).count(), 2);
+        let slices: Vec<ArrayView<i32, 2>> = a.eachslice([2]).collect();
+        assert_eq!(slices.len(), 3);
+        assert_eq!(slices[1].shape, [2, 1]);
+        assert_eq!(slices[1].iter().copied().collect::<Vec<_>>(), vec![2, 5]);
+    }
+
+    #[test]
+    fn reduction_test() {
+        use crate::array_basic::*;
+        let m = matrix![1 2 3; 4 5 6];
+        assert_eq!(m.sum(), 21);
+        assert_eq!(m.prod(), 720);
+        assert_eq!(m.minimum(), Some(1));
+        assert_eq!(m.maximum(), Some(6));
+
+        let col_sums = m.sum_dims([0]);
+        assert_eq!(col_sums.shape, [1, 3]);
+        assert_eq!(col_sums.data, vec![5, 7, 9]);
+        let row_sums = m.to_order(DataOrder::ColMajor).sum_dims([1]); // julia's sum(m; dims=2)
+        assert_eq!(row_sums.shape, [2, 1]);
+        assert_eq!(row_sums.data, vec![6, 15]);
+        assert_eq!(m.sum_dims([0, 1]).data, vec![21]);
+        assert_eq!(m.maximum_dims([1]).data, vec![3, 6]);
+        assert_eq!(m.prod_dims([0]).data, vec![4, 10, 18]);
+
+        let f = m.map(|x| x as f64);
+        assert_eq!(f.mean(), 3.5);
+        assert_eq!(f.mean_dims([1]).data, vec![2.0, 5.0]);
+        // a repeated axis is reduced over once
+        assert_eq!(f.mean_dims([1, 1]).data, vec![2.0, 5.0]);
+        assert_eq!(f.sum_dims([1, 1]).data, f.sum_dims([1]).data);
+        assert_eq!(f.mean_dims([0, 1, 0]).data, vec![3.5]);
+        assert_eq!(
+            f.mapreduce(|x| x * x, |a, b| a + b, 0.0, [0]).data,
+            vec![17.0, 29.0, 45.0]
+        );
+        assert_eq!(
+            m.reduce_axis(1, 0, |acc, x| 10 * acc + x).data,
+            vec![123, 456]
+        );
+        let with_nan = Array {
+            data: vec![1.0, f64::NAN, 0.0],
+            shape: [3],
+            data_order: DataOrder::ColMajor,
+        };
+        assert!(with_nan.minimum().unwrap().is_nan());
+    }
+
+    #[test]
+    fn concatenation_test() {
+        use crate::array_basic::*;
+        use crate::array_error::ShapeError;
+        let a = matrix![1 2; 3 4]; // row-major
+        let b = matrix![5; 6].to_order(DataOrder::ColMajor);
+        let h = hcat!(a, &b, a.transpose_view());
+        assert_eq!(h.shape, [2, 5]);
+        assert_eq!(
+            h.rows().nth(1).unwrap().to_owned().data,
+            vec![3, 4, 6, 2, 4]
+        );
+        let v = vcat!(a, matrix![7 8]);
+        assert_eq!(v.shape, [3, 2]);
+        assert_eq!(v[[2, 1]], 8);
+        let c = cat!(a.reshape([2, 2, 1]), a.reshape([2, 2, 1]); dims = 2);
+        assert_eq!(c.shape, [2, 2, 2]);
+        assert_eq!(c[[1, 0, 1]], 3);
+        assert_eq!(cat!(a, b; axis = 1).data, h.slice(&s![.., ..3]).data);
+        assert!(matches!(
+            Array::try_cat(0, &[a.view(), b.view()]),
+            Err(ShapeError::CatMismatch { axis: 0, .. })
+        ));
+
+        let u = a.slice(&s![0, ..]);
+        let w = a.slice(&s![1, ..]);
+        let columns: Array<i32, 2> = Array::stack(1, &[&u, &w]);
+        assert_eq!(columns.shape, [2, 2]);
+        assert_eq!(columns[[1, 0]], 2);
+        let rows: Array<i32, 2> = Array::stack(0, &[&u, &w]);
+        assert_eq!(rows[[1, 0]], 3);
+        // as in julia, vectors are glued end to end by `vcat!` and taken as columns by `hcat!`
+        assert_eq!(vcat!(u, w).data, vec![1, 2, 3, 4]);
+        assert_eq!(hcat!(u, w).data, columns.data);
+        assert_eq!(Array::hcat(&[&u, &w]).data, columns.data);
+        let bordered = hcat!(range!(7, 1, 8), a, &u);
+        assert_eq!(bordered.shape, [2, 4]);
+        assert_eq!(bordered.data, vec![7, 8, 1, 3, 2, 4, 1, 2]);
+        let mismatch: Result<Array<i32, 2>, _> = Array::try_stack(0, &[&u, &h.slice(&s![0, ..3])]);
+        assert!(matches!(mismatch, Err(ShapeError::ShapeMismatch { .. })));
+        let wrong_rank: Result<Array<i32, 3>, _> = Array::try_stack(0, &[&u, &w]);
+        assert!(matches!(
+            wrong_rank,
+            Err(ShapeError::DimensionMismatch {
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn permutedims_test() {
+        use crate::array_basic::*;
+        let a = matrix![1 2 3; 4 5 6]
+            .reshape_inplace([2, 3, 1])
+            .to_order(DataOrder::ColMajor);
+        let b = a.reshape([1, 2, 3]); // a different arrangement of the same buffer
+        let p = b.permutedims([2, 0, 1]);
+        assert_eq!(p.shape, [3, 1, 2]);
+        assert_eq!(p.data_order, DataOrder::ColMajor);
+        for ([i, j, k], x) in b.indexed_iter() {
+            assert_eq!(p[[k, i, j]], *x);
+            assert_eq!(b.permutedims_view([2, 0, 1])[[k, i, j]], *x);
+        }
+        assert_eq!(
+            p.permutedims([1, 2, 0]).data,
+            b.data,
+            "the inverse permutation restores the array"
+        );
+
+        let m = matrix![1 2 3; 4 5 6];
+        let t = m.permutedims([1, 0]);
+        assert_eq!(t.shape, [3, 2]);
+        assert_eq!(t.data_order, DataOrder::RowMajor);
+        assert_eq!(t.data, vec![1, 4, 2, 5, 3, 6]);
+
+        let mut c = a.clone();
+        c.permutedims_view_mut([2, 1, 0])[[0, 2, 1]] = 60;
+        assert_eq!(c[[1, 2, 0]], 60);
+    }
+
+    #[test]
+    fn numeric_traits_test() {
+        use crate::array_basic::*;
+        use crate::scalar::{AdditiveIdentity, Field, MultiplicativeIdentity, RealFloat, Ring};
+        fn dot<T: Ring>(u: &Array<T, 1>, v: &Array<T, 1>) -> T {
+            (u * v).sum()
+        }
+        fn normalize<T: RealFloat>(v: &Array<T, 1>) -> Array<T, 1> {
+            let norm = dot(v, v).sqrt();
+            v.map(|x| x / norm)
+        }
+        fn harmonic_mean<T: Field>(values: &[T]) -> T {
+            let count = values.iter().fold(T::ZERO, |acc, _| acc + T::ONE);
+            count
+                / values
+                    .iter()
+                    .fold(T::ZERO, |acc, x| acc + T::ONE / x.clone())
+        }
+        let v = matrix![3.0 4.0].reshape([2]);
+        assert_eq!(dot(&v, &v), 25.0);
+        assert_eq!(normalize(&v).data, vec![0.6, 0.8]);
+        assert_eq!(harmonic_mean(&[1.0f32, 4.0, 4.0]), 2.0);
+        assert_eq!(<u8 as AdditiveIdentity>::ZERO, 0);
+        assert_eq!(<i64 as MultiplicativeIdentity>::ONE, 1);
+    }
+
+    #[test]
+    fn complex_test() {
+        use num_complex::Complex;
+        let i: Complex<f64> = Complex::new(0.0, 1.0);
+        let one: Complex<f64> = Complex::new(1.0, 0.0);
+        let m = matrix![one, i; -i, one * 2.0];
+        let p = m.mul_naive(&m.adjoint());
+        assert_eq!(p[[0, 1]], Complex::new(0.0, 3.0));
+        assert_eq!(p[[1, 1]], Complex::new(5.0, 0.0));
+        assert_eq!(m.adjoint()[[0, 1]], i);
+        assert_eq!((i * &m)[[1, 0]], one);
+
+        assert_eq!(m.conj()[[0, 1]], -i);
+        assert_eq!(m.real().data, vec![1.0, 0.0, 0.0, 2.0]);
+        assert_eq!(m.imag().data, vec![0.0, 1.0, -1.0, 0.0]);
+        assert_eq!(m.abs().data, vec![1.0, 1.0, 1.0, 2.0]);
+        assert_eq!(m.angle()[[1, 0]], -std::f64::consts::FRAC_PI_2);
+        assert_eq!(matrix![-2.0 3.0].abs().data, vec![2.0, 3.0]);
+
+        let printed = format!("{m}");
+        assert!(printed.contains("1.000000 + 0.000000im"));
+        assert!(printed.contains("0.000000 - 1.000000im"));
+    }
+
+    #[test]
+    fn mask_test() {
+        let mut a = matrix![1, -2, 3; -4, 5, -6];
+        let positive = a.gt(&0);
+        assert_eq!(positive.shape, [2, 3]);
+        assert_eq!(positive.iter().filter(|&&x| x).count(), 3);
+        assert!(positive[[1, 1]] && !positive[[1, 0]]);
+        assert_eq!(a.select(&positive).data, vec![1, 5, 3]); // column-major order
+        assert_eq!(a.le(&matrix![0; 5]).count(), 4); // a column broadcast along the rows
+
+        let multiple_of_three = a.map(|x| x % 3 == 0);
+        assert_eq!(positive.and(&multiple_of_three).count(), 1);
+        assert_eq!(positive.or(&multiple_of_three).not().count(), 2);
+        assert_eq!(a.ne(&a.view()).count(), 0);
+
+        a.assign_where(&a.lt(&0), 0); // julia's a[a .< 0] .= 0
+        assert_eq!(a.data, vec![1, 0, 3, 0, 5, 0]);
+        let mut first_row = a.slice_view_mut(&s![0, ..]);
+        let threes = first_row.view().eq(&3);
+        first_row.assign_where(&threes, 7);
+        assert_eq!(a.data, vec![1, 0, 7, 0, 5, 0]);
+    }
+
+    #[test]
+    fn search_and_sort_test() {
+        use crate::array_basic::*;
+        let m = matrix![3, 9, 1; 7, 9, 0];
+        assert_eq!(m.argmax(), Some([0, 1])); // the first maximum in column-major order
+        assert_eq!(m.argmin(), Some([1, 2]));
+        assert_eq!(m.argmax_axis(1).data, vec![1, 1]);
+        assert_eq!(m.argmin_axis(0).data, vec![0, 0, 1]);
+        assert_eq!(m.findall(|&x| x > 5), vec![[1, 0], [0, 1], [1, 1]]);
+
+        let sorted = m.sort(1);
+        assert_eq!(sorted.rows().nth(1).unwrap().to_owned().data, vec![0, 7, 9]);
+        assert_eq!(m.sort_stable(0)[[0, 2]], 0);
+        let perm = m.sortperm_stable(1);
+        assert_eq!(perm.rows().next().unwrap().to_owned().data, vec![2, 0, 1]);
+
+        let v = Array {
+            data: vec![2.0, f64::NAN, -1.0, 2.0],
+            shape: [4],
+            data_order: DataOrder::ColMajor,
+        };
+        assert_eq!(v.argmax(), Some([1]));
+        assert_eq!(v.argmin(), Some([1]));
+        let sorted = v.sort(0);
+        assert_eq!(&sorted.data[..3], &[-1.0, 2.0, 2.0]);
+        assert!(sorted.data[3].is_nan());
+        assert_eq!(v.sortperm_stable(0).data, vec![2, 0, 3, 1]);
+        assert_eq!(v.sortperm(0)[[3]], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "axis 2 is out of bounds for an array of dimension 2")]
+    fn sort_axis_out_of_bounds_test() {
+        let m = matrix![3 1 2; 6 5 4];
+        let _ = m.sortperm(2);
+    }
+
+    #[test]
+    fn gather_scatter_test() {
+        use crate::array_basic::*;
+        use crate::array_error::ShapeError;
+        let a = matrix![1 2 3; 4 5 6; 7 8 9];
+        let rows = a.take(0, &[2, 0, 2]); // julia's a[[3, 1, 3], :]
+        assert_eq!(rows.shape, [3, 3]);
+        assert_eq!(rows.data_order, DataOrder::RowMajor);
+        assert_eq!(rows.data, vec![7, 8, 9, 1, 2, 3, 7, 8, 9]);
+        let cols = a.to_order(DataOrder::ColMajor).take(1, &[1]);
+        assert_eq!(cols.data, vec![2, 5, 8]);
+        assert!(matches!(
+            a.try_take(1, &[0, 3]),
+            Err(ShapeError::IndexOutOfBounds { axis: 1, .. })
+        ));
+
+        let mut b = a.clone();
+        b.put(1, &[0, 0], &matrix![10 20; 30 40; 50 60]); // the last of the repeated indices wins
+        assert_eq!(b.cols().next().unwrap().to_owned().data, vec![20, 40, 60]);
+
+        let mut histogram = Array {
+            data: vec![0; 3],
+            shape: [3],
+            data_order: DataOrder::ColMajor,
+        };
+        let ones = matrix![1 1 1 1].reshape([4]);
+        histogram.scatter_add(0, &[2, 0, 2, 2], &ones);
+        assert_eq!(histogram.data, vec![1, 0, 3]);
+        assert!(matches!(
+            histogram.try_scatter_add(0, &[0], &ones),
+            Err(ShapeError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn constructor_test() {
+        use crate::array_basic::*;
+        let z = zeros!(f64, 2, 3, 4);
+        assert_eq!(z.shape, [2, 3, 4]);
+        assert!(z.data.iter().all(|&x| x == 0.0));
+        let o = ones!(i32, 2, 3; DataOrder::RowMajor);
+        assert_eq!((o.data_order, o.sum()), (DataOrder::RowMajor, 6));
+        assert_eq!(fill!(7u8, 4).data, vec![7; 4]);
+
+        // from_fn fills the buffer in the requested order
+        let f = from_fn!(|[i, j]| i * 10 + j, 2, 3; DataOrder::RowMajor);
+        assert_eq!(f.data, vec![0, 1, 2, 10, 11, 12]);
+        let g = Array::from_fn([2, 3], DataOrder::ColMajor, |[i, j]| i * 10 + j);
+        assert_eq!(g.data, vec![0, 10, 1, 11, 2, 12]);
+        assert_eq!(f[[1, 2]], g[[1, 2]]);
+
+        let i3 = eye!(f64, 3);
+        assert_eq!(i3.mul_naive(&f.map(|x| x as f64).transpose()).data.len(), 6);
+        assert_eq!(i3.sum(), 3.0);
+        let v = range!(1, 1, 3);
+        let d = diagm!(v, -1; DataOrder::RowMajor);
+        assert_eq!(d.shape, [4, 4]);
+        assert_eq!(d.data, vec![0, 0, 0, 0, 1, 0, 0, 0, 0, 2, 0, 0, 0, 0, 3, 0]);
+        assert_eq!(diagm!(v, 1)[[0, 1]], 1);
+
+        assert_eq!(range!(0.0, 0.25, 1.0).data, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(range!(5, -2, 0).data, vec![5, 3, 1]);
+        assert!(range!(0, 1, -1).data.is_empty());
+        // ranges ending at the bounds of integer types, where `x + step` would overflow
+        assert_eq!(range!(250u8, 3, 255).data, vec![250, 253]);
+        assert_eq!(range!(252u8, 3, 255).data, vec![252, 255]);
+        assert_eq!(
+            range!(i32::MAX - 2, 2, i32::MAX).data,
+            vec![i32::MAX - 2, i32::MAX]
+        );
+        assert_eq!(range!(-125i8, -2, i8::MIN).data, vec![-125, -127]);
+        assert_eq!(range!(-100i8, 100, 100).data, vec![-100, 0, 100]);
+        assert_eq!(range!(u64::MAX, 1, u64::MAX).data, vec![u64::MAX]);
+        assert_eq!(range!(i8::MIN, 1, i8::MAX).shape, [256]);
+        assert_eq!(range!(-1.0, 0.5, 1.0).data, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let l = linspace!(0.0, 1.0, 11);
+        assert_eq!(l.shape, [11]);
+        assert_eq!((l[[0]], l[[10]]), (0.0, 1.0));
+        assert!((l[[3]] - 0.3f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn random_test() {
+        use crate::array_basic::*;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use rand_distr::{Exp, Normal, Uniform};
+        let a = randn!(f64, 3, 4; rng = &mut StdRng::seed_from_u64(42));
+        let b = randn!(f64, 3, 4; rng = &mut StdRng::seed_from_u64(42));
+        assert_eq!(a.shape, [3, 4]);
+        assert_eq!(a.data, b.data);
+        assert_ne!(
+            a.data,
+            randn!(f64, 3, 4; rng = &mut StdRng::seed_from_u64(7)).data
+        );
+
+        // a true standard normal: mean near 0, variance near 1 and about a third of the samples beyond one sigma
+        let mut rng = StdRng::seed_from_u64(0);
+        let n = Array::<f64, 1>::randn([100_000], &mut rng);
+        let mean = n.mean();
+        let variance = n.map(|x| (x - mean) * (x - mean)).mean();
+        assert!(mean.abs() < 0.02 && (variance - 1.0).abs() < 0.02);
+        assert!(n.data.iter().any(|&x| x < 0.0));
+        let tails = n.data.iter().filter(|x| x.abs() > 1.0).count() as f64 / 1e5;
+        assert!((tails - 0.3173).abs() < 0.01);
+
+        let u = rand!(f32, 2, 5; rng = &mut rng);
+        assert!(u.data.iter().all(|&x| (0.0..1.0).contains(&x)));
+        let dice = Array::random([1000], Uniform::new_inclusive(1, 6), &mut rng);
+        assert_eq!((dice.minimum(), dice.maximum()), (Some(1), Some(6)));
+        let normal = Array::random([2, 2], Normal::new(10.0, 0.1).unwrap(), &mut rng);
+        assert!(normal.data.iter().all(|&x| (9.0..11.0).contains(&x)));
+        let exponential = Array::random([1000], Exp::new(2.0).unwrap(), &mut rng);
+        assert!(exponential.data.iter().all(|&x: &f64| x >= 0.0));
+        assert!((exponential.mean() - 0.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn zero_copy_test() {
+        use crate::array_basic::*;
+        use crate::array_error::ShapeError;
+        use crate::array_view::{ArrayView, ArrayViewMut};
+        let buffer = vec![1, 2, 3, 4, 5, 6];
+        let pointer = buffer.as_ptr();
+        let a = Array::from_shape_vec([2, 3], buffer, DataOrder::RowMajor);
+        assert_eq!(a.data.as_ptr(), pointer); // the Vec is moved, not copied
+        assert_eq!(a[[1, 0]], 4);
+        assert!(matches!(
+            Array::try_from_shape_vec([4, 2], vec![0; 6], DataOrder::ColMajor),
+            Err(ShapeError::SizeMismatch { .. })
+        ));
+
+        let frame = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let v = ArrayView::from_slice(&frame, [2, 3], DataOrder::ColMajor);
+        assert_eq!((v[[1, 0]], v[[0, 2]]), (2.0, 5.0));
+        assert_eq!(v.data.as_ptr(), frame.as_ptr());
+        assert!(ArrayView::try_from_slice(&frame, [2, 2], DataOrder::ColMajor).is_err());
+
+        let mut raw = [0; 6];
+        let mut m = ArrayViewMut::from_slice(&mut raw, [2, 3], DataOrder::RowMajor);
+        m[[1, 0]] = 7;
+        assert_eq!(raw, [0, 0, 0, 7, 0, 0]);
+
+        // every second element of the buffer, walked backwards from the last one
+        let reversed = unsafe { ArrayView::from_raw_parts(frame.as_ptr().add(5), [3], [-2]) };
+        assert_eq!(reversed.to_owned().data, vec![6.0, 4.0, 2.0]);
+        let mut block = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut inner =
+            unsafe { ArrayViewMut::from_raw_parts_mut(block.as_mut_ptr().add(4), [2, 2], [1, 3]) };
+        inner.fill(0);
+        assert_eq!(block, [1, 2, 3, 4, 0, 0, 7, 0, 0]);
+        // an empty buffer may come as a null pointer, which is never read
+        let empty =
+            unsafe { ArrayView::<f64, 2>::from_raw_parts(std::ptr::null(), [0, 3], [1, 0]) };
+        assert_eq!(empty.to_owned().shape, [0, 3]);
+        let empty =
+            unsafe { ArrayViewMut::<i32, 1>::from_raw_parts_mut(std::ptr::null_mut(), [0], [1]) };
+        assert_eq!(empty.data.len(), 0);
+    }
+
+    #[test]
+    fn aligned_storage_test() {
+        use crate::array_aligned::{AlignedArray, PaddedArray};
+        use crate::array_basic::*;
+        let a: AlignedArray<f64, 2, 64> =
+            Array::from_fn([3, 5], DataOrder::ColMajor, |[i, j]| (i * 5 + j) as f64).into_aligned();
+        assert_eq!(a.alignment(), 64);
+        assert_eq!(a.data.as_ptr() as usize % 64, 0);
+        assert_eq!(a[[2, 4]], 14.0);
+
+        // alignment survives consuming operations, also when the result outgrows the buffer
+        let b = (a.clone() * 2.0 + &a).map_inplace(|x| x - 1.0);
+        assert_eq!(b.data.as_ptr() as usize % 64, 0);
+        assert_eq!(b[[2, 4]], 41.0);
+        let c = b.reshape_inplace([5, 3]) - ones!(f64, 5, 3, 2);
+        assert_eq!(c.shape, [5, 3, 2]);
+        assert_eq!(c.data.as_ptr() as usize % 64, 0);
+        let d = -c + 1.0;
+        assert_eq!(d.alignment(), 64);
+
+        let (chunks, tail) = a.as_simd_chunks::<4>();
+        assert_eq!((chunks.len(), tail.len()), (3, 3));
+        assert_eq!(chunks[1].to_array(), [6.0, 11.0, 2.0, 7.0]);
+
+        // every column of a 3x2 column-major matrix padded to whole f64x4 vectors
+        let p = PaddedArray::<f64, 2, 32>::from_view(
+            &matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0],
+            DataOrder::ColMajor,
+        );
+        assert_eq!(p.leading_dimension(), 4);
+        assert_eq!(p.storage.data, vec![1.0, 3.0, 5.0, 0.0, 2.0, 4.0, 6.0, 0.0]);
+        assert_eq!(p.view().to_owned().data, vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0]);
+        let lines: Vec<_> = p.simd_lines::<4>().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1][0].to_array(), [2.0, 4.0, 6.0, 0.0]);
+        let scalar = PaddedArray::<f64, 0, 32>::zeros([], DataOrder::ColMajor);
+        assert_eq!(scalar.simd_lines::<4>().count(), 0);
+
+        let m = Array::from_fn([5, 7], DataOrder::RowMajor, |[i, j]| {
+            (i as f64) - 0.5 * j as f64
+        });
+        let n = Array::from_fn([7, 3], DataOrder::ColMajor, |[i, j]| (i * j) as f64 + 1.0);
+        assert_eq!(m.mul_simd(&n).data, m.mul_naive(&n).data);
+        let m32 = m.map(|x| x as f32);
+        assert_eq!(m32.mul_simd(&m32.transpose_view()).shape, [5, 5]);
+
+        // empty buffers and operands, whose dangling pointers are not aligned to a whole vector
+        let empty: AlignedArray<f64, 1, 32> =
+            Array::from_shape_vec([0], vec![], DataOrder::ColMajor).into_aligned();
+        let (chunks, tail) = empty.as_simd_chunks::<4>();
+        assert!(chunks.is_empty() && tail.is_empty());
+        let short: AlignedArray<f64, 1, 32> =
+            Array::from_shape_vec([3], vec![1.0; 3], DataOrder::ColMajor).into_aligned();
+        assert_eq!(short.as_simd_chunks::<4>().1.len(), 3);
+        let k = Array::<f64, 2>::zeros([0, 3], DataOrder::RowMajor);
+        assert_eq!(k.mul_simd(&n.slice_view(&s![0..3, ..])).shape, [0, 3]);
+        assert_eq!(
+            n.mul_simd(&Array::<f64, 2>::zeros([3, 0], DataOrder::ColMajor))
+                .shape,
+            [7, 0]
+        );
+        let zero_inner = Array::<f64, 2>::zeros([2, 0], DataOrder::RowMajor);
+        assert_eq!(
+            zero_inner.mul_simd(&zero_inner.transpose_view()).data,
+            vec![0.0; 4]
+        );
+    }
+
+    #[test]
+    fn static_array_test() {
+        use crate::array_basic::*;
+        use crate::array_static::{SArray, SArrayN};
+        let m = SArray::<f64, 2, 3>::from_fn(|[i, j]| (i * 3 + j) as f64);
+        let a = Array::from(m);
+        assert_eq!((a.shape, a.data_order), ([2, 3], DataOrder::ColMajor));
+        assert_eq!(a.data, vec![0.0, 3.0, 1.0, 4.0, 2.0, 5.0]);
+        assert_eq!(
+            SArray::<f64, 2, 3>::from_view(&a.to_order(DataOrder::RowMajor)),
+            m
+        );
+        assert!(SArray::<f64, 3, 2>::try_from(&a).is_err());
+        assert_eq!((m * m.transpose()).data, [[5.0, 14.0], [14.0, 50.0]]);
+        assert_eq!(
+            Array::from(m * m.transpose()).data,
+            a.mul_naive(&a.transpose())
+                .to_order(DataOrder::ColMajor)
+                .data
+        );
+        // the same grid of elements as the equivalent `Array`
+        let grid = |s: String| s.lines().skip(2).collect::<Vec<_>>().join("\n");
+        assert_eq!(grid(format!("{m}")), grid(format!("{a}")));
+
+        fn assert_near<const N: usize>(m: SArray<f64, N, N>, expected: SArray<f64, N, N>) {
+            let diff = m - expected;
+            assert!(diff.data.iter().flatten().all(|x| x.abs() < 1e-12), "{m}");
+        }
+        let r = SArray::<f64, 2, 2>::from_view(&matrix![4.0, 7.0; 2.0, 6.0]);
+        assert_eq!(r.determinant(), 10.0);
+        assert_near(r.inverse().unwrap() * r, SArray::eye());
+        let t =
+            SArray::<f64, 3, 3>::from_view(&matrix![2.0, 0.0, 1.0; 1.0, 3.0, 2.0; 1.0, 1.0, 2.0]);
+        assert_eq!(t.determinant(), 6.0);
+        assert_near(t * t.inverse().unwrap(), SArray::eye());
+        let q = SArray::<f64, 4, 4>::from_view(&matrix![
+            1.0, 2.0, 0.0, 1.0;
+            0.0, 1.0, 3.0, 0.0;
+            2.0, 0.0, 1.0, 4.0;
+            1.0, 1.0, 0.0, 2.0
+        ]);
+        assert_eq!(q.determinant(), 7.0);
+        assert_eq!(q.transpose().determinant(), q.determinant());
+        assert_near(q * q.inverse().unwrap(), SArray::eye());
+        assert!(SArray::<f64, 3, 3>::zeros().inverse().is_none());
+
+        let cube = SArrayN::<i32, 8, 3>::from_fn([2, 2, 2], |[i, j, k]| (i + 2 * j + 4 * k) as i32);
+        assert_eq!(cube.data, [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(cube[[1, 0, 1]], 5);
+        assert_eq!(Array::from(cube).sum(), 28);
+        assert!(SArrayN::<i32, 8, 2>::try_from_fn([3, 3], |_| 0).is_err());
+    }
+
+    #[test]
+    fn shared_storage_test() {
+        use crate::array_shared::ArcArray;
+        let m = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        let buffer = m.data.as_ptr();
+        let a = m.into_shared();
+        assert_eq!(a.data.as_ptr(), buffer);
+
+        // clones and reshapes share the buffer until the first write
+        let mut b = a.clone();
+        let r = a.reshape([3, 2]);
+        assert!(b.shares_data_with(&a) && r.shares_data_with(&a) && !a.is_unique());
+        assert!(a.try_reshape([4, 2]).is_err());
+        b[[0, 1]] = 20.0;
+        assert!(!b.shares_data_with(&a) && b.is_unique());
+        assert_eq!((a[[0, 1]], b[[0, 1]], b[[1, 2]]), (2.0, 20.0, 6.0));
+        b.view_mut().slice_view_into(&s![.., 0]).fill(0.0);
+        b.map_mut(|x| *x += 1.0);
+        assert_eq!(b.data.as_slice(), &[1.0, 21.0, 4.0, 1.0, 6.0, 7.0][..]);
+        assert_eq!(
+            (a.data.as_slice(), r[[2, 1]]),
+            (&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0][..], 6.0)
+        );
+        assert_eq!(a.map(|x| x as i32).data.as_slice(), &[1, 2, 3, 4, 5, 6][..]);
+
+        // clones cross threads without copying the elements
+        let (left, right) = rayon::join(|| a.clone().view().sum(), || r.view().sum());
+        assert_eq!((left, right), (21.0, 21.0));
+        fn assert_send_sync<S: Send + Sync>(_: &S) {}
+        assert_send_sync(&a);
+
+        // a unique buffer returns to `Array` without copy, a shared one is copied
+        drop(r);
+        let owned = a.clone().into_owned();
+        assert_ne!(owned.data.as_ptr(), buffer);
+        assert_eq!(a.into_owned().data.as_ptr(), buffer);
+        assert_eq!(owned.data, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(format!("{}", ArcArray::from(owned)).contains("ArcArray<f64, [2, 3]>"));
+    }
+
+    #[test]
+    fn sparse_test() {
+        use crate::array_basic::*;
+        use crate::array_error::ShapeError;
+        use crate::matrix_sparse::{SparseBuilder, SparseCSC};
+        // triplets in any order, with duplicates at [2, 0] summed
+        let mut builder = SparseBuilder::with_capacity([3, 4], 5);
+        builder.push(2, 0, 1.0);
+        builder.extend([(0, 0, 2.0), (1, 2, 3.0), (2, 0, 4.0), (0, 3, -1.0)]);
+        assert!(matches!(
+            builder.try_push(3, 0, 1.0),
+            Err(ShapeError::IndexOutOfBounds { axis: 0, .. })
+        ));
+        let a = builder.build();
+        assert_eq!(a.colptr, vec![0, 2, 2, 3, 4]);
+        assert_eq!(a.rowval, vec![0, 2, 1, 0]);
+        assert_eq!(a.nzval, vec![2.0, 5.0, 3.0, -1.0]);
+        assert_eq!(
+            (a.get([2, 0]), a.get([1, 1]), a.get([5, 0])),
+            (Some(&5.0), None, None)
+        );
+
+        // round trip through dense storage of either layout
+        let dense = matrix![2.0, 0.0, 0.0, -1.0; 0.0, 0.0, 3.0, 0.0; 5.0, 0.0, 0.0, 0.0];
+        assert_eq!(SparseCSC::from_dense(&dense), a);
+        assert_eq!(a.to_dense().data, dense.to_order(DataOrder::ColMajor).data);
+        assert_eq!(
+            a.transpose(),
+            SparseCSC::from_dense(&dense.transpose_view())
+        );
+        assert_eq!(a.transpose().transpose(), a);
+
+        // sparse times dense agrees with the dense product
+        let x = Array::from_fn([4], DataOrder::ColMajor, |[i]| i as f64 + 1.0);
+        assert_eq!(a.mul_vector(&x).data, vec![-2.0, 9.0, 5.0]);
+        let b = Array::from_fn([4, 2], DataOrder::RowMajor, |[i, j]| (i * 2 + j) as f64);
+        assert_eq!(a.mul_dense(&b).data, dense.mul_naive(&b).data);
+        assert!(a.try_mul_dense(&dense).is_err());
+
+        // sparse-sparse sums and products drop the entries that cancel
+        let c = SparseCSC::from_triplets([3, 4], [(0, 0, -2.0), (1, 1, 1.0)]);
+        let sum = &a + &c;
+        assert_eq!(sum.nnz(), 4);
+        assert_eq!(
+            sum.to_dense().data,
+            (dense.to_order(DataOrder::ColMajor) + c.to_dense()).data
+        );
+        assert!(a.try_add(&a.transpose()).is_err());
+        let product = a.mul_sparse(&a.transpose());
+        assert_eq!(
+            product.to_dense().data,
+            dense.mul_naive(&dense.transpose_view()).data
+        );
+        assert_eq!(product.nnz(), 5);
+        let integers = SparseCSC::from_triplets([2, 2], [(0, 1, 1), (1, 0, 1)]);
+        assert_eq!(
+            integers.mul_sparse(&integers),
+            SparseCSC::from_triplets([2, 2], [(0, 0, 1), (1, 1, 1)])
+        );
+
+        let mut explicit = SparseCSC::from_triplets([2, 2], [(0, 0, 1), (0, 0, -1), (1, 1, 2)]);
+        assert_eq!(explicit.nnz(), 2);
+        explicit.dropzeros();
+        assert_eq!((explicit.colptr, explicit.nzval), (vec![0, 0, 1], vec![2]));
+        assert!(format!("{a}").contains("  [2, 0]  =  5.000000"));
+    }
+
     // fn matrix_multiplication_test(dim: usize) {
     // let m1 = matrix![1 2 3; 4 5 6];
     // let m2 = randn!(f64, dim, dim);
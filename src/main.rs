@@ -1,49 +1,61 @@
 #![feature(portable_simd)]
+#![feature(allocator_api)]
 
 // use std::simd::{f64x64, f64x8};
 
 use rs_bench::*;
 
+mod array_aligned;
 mod array_basic;
+mod array_broadcast;
+mod array_complex;
+mod array_concat;
 mod array_display;
+mod array_dyn;
+mod array_error;
+mod array_index;
+mod array_iter;
+mod array_layout;
+mod array_mask;
+mod array_new;
+mod array_ops;
+mod array_random;
+mod array_reduce;
+mod array_shared;
+mod array_slice;
+mod array_static;
+mod array_view;
 mod matrix;
-// mod matrix_simd;
-// mod array_slice;
+mod matrix_sparse;
 mod scalar;
 
+use array_aligned::AlignedArray;
+
 // use rayon::prelude::*;
 
-fn dot_simd(lhs: &[f64], rhs: &[f64]) -> f64 {
+fn dot_simd(lhs: &AlignedArray<f64, 1, 32>, rhs: &AlignedArray<f64, 1, 32>) -> f64 {
     use std::simd::{num::SimdFloat, Simd};
-    let lhs_len = lhs.len();
-    let rhs_len = rhs.len();
-    assert_eq!(lhs_len, rhs_len);
-    const SIMD_WIDTH: usize = 4; // AVX2 supports 256 bits, so 8 f64 values
+    assert_eq!(lhs.shape, rhs.shape);
+    const SIMD_WIDTH: usize = 4; // AVX2 supports 256 bits, so 4 f64 values
 
-    // let mut res_simd: Simd<f64, SIMD_WIDTH> = Simd::splat(0.0 as f64);
-    // // Process in chunks of SIMD_WIDTH
-    // let mut index: usize = 0; // chunk index
-    let residule_len = lhs_len % SIMD_WIDTH;
-    let res = lhs
-        .chunks_exact(SIMD_WIDTH)
-        .map(Simd::from_slice)
-        .zip(rhs.chunks_exact(SIMD_WIDTH).map(Simd::from_slice))
+    // both buffers start at a 32-byte boundary, so the chunks are read with aligned loads
+    let (lhs_simd, lhs_residule) = lhs.as_simd_chunks::<SIMD_WIDTH>();
+    let (rhs_simd, rhs_residule) = rhs.as_simd_chunks::<SIMD_WIDTH>();
+    let res = lhs_simd
+        .iter()
+        .zip(rhs_simd.iter())
         .map(|(lhs_simd, rhs_simd)| {
             lhs_simd * rhs_simd // simd dot product, still resulting in a simd vector
         })
         .sum::<Simd<_, SIMD_WIDTH>>()
         .reduce_sum(); // horizontal sum of the simd vector
 
-    if residule_len == 0 {
-        res
-    } else {
-        lhs.iter()
-            .skip(lhs_len - residule_len)
-            .zip(rhs.iter().skip(rhs_len - residule_len))
-            .map(|(a, b)| a * b)
-            .sum::<f64>()
-            + res
-    }
+    lhs_residule
+        .iter()
+        .zip(rhs_residule.iter())
+        .map(|(a, b)| a * b)
+        .sum::<f64>()
+        + res
 }
 
 fn main() {
@@ -62,9 +74,19 @@ fn main() {
     //     // println!("{res}");
     // }];
 
-    // time_block![{
-    //     let res = dot_simd(&m1, &m2);
-    //     // println!("{res}")
-    // }];
+    // a length that is not a multiple of the lanes, so that both the aligned chunks and the tail are used
+    let v1: AlignedArray<f64, 1, 32> = randn!(f64, n * n + 3).into_aligned();
+    let v2: AlignedArray<f64, 1, 32> = randn!(f64, n * n + 3).into_aligned();
+    time_block![{
+        let res = dot_simd(&v1, &v2);
+        let expected = v1
+            .data
+            .iter()
+            .zip(v2.data.iter())
+            .map(|(a, b)| a * b)
+            .sum::<f64>();
+        assert!((res - expected).abs() < 1e-12);
+        println!("{res}")
+    }];
     // println!("test");
 }
@@ -1,10 +1,11 @@
 use crate::{
+    array_aligned::PaddedArray,
     array_basic::*,
-    calculate_data_index,
-    scalar::{Arithmetic, Scalar},
+    array_error::ShapeError,
+    array_view::{ArrayView, AsArrayView},
+    scalar::{Arithmetic, Conjugate, RealFloat, Ring, Scalar},
 };
-use num_traits::*;
-use std::ops::{Add, Mul, Sub};
+use std::simd::{num::SimdFloat, Simd};
 
 /* Macros */
 #[macro_export]
@@ -20,7 +21,7 @@ macro_rules! matrix {
             let data = vec![$( $($x),+ ),*];
             let array = Array {
                 data,
-                shape: vec![col_len,row_len],
+                shape: [row_len,col_len],
                 data_order: DataOrder::RowMajor,
             };
             array
@@ -37,7 +38,7 @@ macro_rules! matrix {
             let data = vec![$( $($x),+ ),*];
             let array = Array {
                 data,
-                shape: [col_len,row_len],
+                shape: [row_len,col_len],
                 data_order: DataOrder::RowMajor,
             };
             array
@@ -48,6 +49,8 @@ macro_rules! matrix {
 /* Implementations for array of two dimensions, i.e., Matrices */
 impl<T: Scalar> Array<T, 2> {
     /// transpose of **two-dimensional** `Array<T>`
+    ///
+    /// The buffer is cloned as is and only `data_order` is flipped; see `permutedims` for arrays of any dimension.
     pub fn transpose(&self) -> Array<T, 2> {
         // let mut res_data = Vec::<T>::with_capacity(self.data.len());
         let mut res_data = self.data.clone();
@@ -73,51 +76,152 @@ impl<T: Scalar> Array<T, 2> {
             data_order: self.data_order.alternate(),
         }
     }
+    /// conjugate transpose of **two-dimensional** `Array<T>`, as julia's `adjoint(A)` or `A'`
+    ///
+    /// Like `transpose`, the result keeps the memory layout of `self` and flips `data_order`; for real element types it equals `transpose`.
+    pub fn adjoint(&self) -> Array<T, 2>
+    where
+        T: Conjugate,
+    {
+        Array {
+            data: self.data.iter().map(Conjugate::conj).collect(),
+            shape: [self.shape[1], self.shape[0]],
+            data_order: self.data_order.alternate(),
+        }
+    }
+    /// zero-copy transpose of **two-dimensional** `Array<T>` as a view
+    pub fn transpose_view(&self) -> ArrayView<'_, T, 2> {
+        self.view().transpose()
+    }
 }
 
 // Mul<Output = T> + Add<Output = T>
-impl<T: Scalar + Arithmetic<T>> Array<T, 2> {
-    /// check dimension and multiplication relevant length
-    #[inline]
-    fn matrix_multiplication_check(lhs: &Array<T, 2>, rhs: &Array<T, 2>) {
-        assert!(lhs.shape[1] == rhs.shape[0]);
-    }
-
+impl<'a, T: Scalar + Arithmetic<T>> ArrayView<'a, T, 2> {
     /// Naive `O(n^3)` multiplication
-    pub fn mul_naive(&self, rhs: &Array<T, 2>) -> Array<T, 2> {
-        Array::matrix_multiplication_check(self, rhs);
+    ///
+    /// The operands are normalized first (rows of `self` and columns of `rhs` made contiguous), so that the innermost loop is a dot product over two slices whatever their `data_order`.
+    pub fn mul_naive<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Array<T, 2> {
+        self.try_matmul(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `mul_naive`, returning `ShapeError::InnerDimensionMismatch` if the columns of `self` do not match the rows of `rhs`
+    pub fn try_matmul<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Result<Array<T, 2>, ShapeError> {
+        let rhs = rhs.as_view();
+        Array::matrix_multiplication_check(self, &rhs)?;
 
-        let (res_row, res_col) = (self.shape[0], self.shape[1]);
+        let (res_row, res_col, inner) = (self.shape[0], rhs.shape[1], self.shape[1]);
+        let lhs_data = self.as_contiguous_in(DataOrder::RowMajor);
+        let rhs_data = rhs.as_contiguous_in(DataOrder::ColMajor);
         let mut res_data = Vec::with_capacity(res_row * res_col);
 
-        // let self_transpose = self.transpose();
-
         // switch the order of the loops to improve cache hit rate
         for j in 0..res_col {
+            let rhs_col = &rhs_data[j * inner..(j + 1) * inner];
             for i in 0..res_row {
-                let mut sum = T::ZERO;
-                for k in 0..self.shape[1] {
-                    let lhs_index = calculate_data_index!(self, [i, k]);
-                    let rhs_index = calculate_data_index!(rhs, [k, j]);
-                    sum = sum + self.data[lhs_index].clone() * rhs.data[rhs_index].clone();
-                    // it is OK to use `.clone()` here when datatype support copy trait: the compiler is smart enough to replace with stack-copy and skip the overhead
-                }
+                let lhs_row = &lhs_data[i * inner..(i + 1) * inner];
+                let sum = lhs_row
+                    .iter()
+                    .zip(rhs_col.iter())
+                    .fold(T::ZERO, |acc, (a, b)| acc + a.clone() * b.clone());
+                // it is OK to use `.clone()` here when datatype support copy trait: the compiler is smart enough to replace with stack-copy and skip the overhead
                 res_data.push(sum);
             }
         }
-        Array {
+        Ok(Array {
             data: res_data,
             shape: [res_row, res_col],
-            data_order: self.data_order,
+            data_order: DataOrder::ColMajor, // the result is filled column by column
+        })
+    }
+}
+
+impl<T: Scalar + Arithmetic<T>> Array<T, 2> {
+    /// check dimension and multiplication relevant length
+    #[inline]
+    fn matrix_multiplication_check(
+        lhs: &ArrayView<T, 2>,
+        rhs: &ArrayView<T, 2>,
+    ) -> Result<(), ShapeError> {
+        if lhs.shape[1] == rhs.shape[0] {
+            return Ok(());
         }
+        Err(ShapeError::InnerDimensionMismatch {
+            lhs: lhs.shape.to_vec(),
+            rhs: rhs.shape.to_vec(),
+        })
     }
 
+    /// Naive `O(n^3)` multiplication
+    pub fn mul_naive<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Array<T, 2> {
+        self.view().mul_naive(rhs)
+    }
+    /// Fallible `mul_naive`, see `ArrayView::try_matmul`
+    pub fn try_matmul<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Result<Array<T, 2>, ShapeError> {
+        self.view().try_matmul(rhs)
+    }
+}
+
+/// Implement `mul_simd` for floating-point element types with vectors of `$lanes` elements in 32-byte aligned storage
+macro_rules! impl_mul_simd {
+    ($($type:ty, $lanes:literal);+ $(;)?) => {$(
+        impl<'a> ArrayView<'a, $type, 2> {
+            /// Multiplication with explicit SIMD
+            ///
+            /// The rows of `self` and the columns of `rhs` are copied into aligned storage padded with zeros to whole vectors, so that every dot product runs on aligned loads without a remainder loop.
+            pub fn mul_simd<V: AsArrayView<$type, 2>>(&self, rhs: &V) -> Array<$type, 2> {
+                let rhs = rhs.as_view();
+                Array::matrix_multiplication_check(self, &rhs)
+                    .unwrap_or_else(|error| panic!("{error}"));
+                let shape = [self.shape[0], rhs.shape[1]];
+                // an empty operand has no lines to load, and the product is zero (or empty)
+                if shape.contains(&0) || self.shape[1] == 0 {
+                    return Array::zeros(shape, DataOrder::ColMajor);
+                }
+                let lhs = PaddedArray::<$type, 2, 32>::from_view(self, DataOrder::RowMajor);
+                let rhs = PaddedArray::<$type, 2, 32>::from_view(&rhs, DataOrder::ColMajor);
+                let lhs_rows: Vec<&[Simd<$type, $lanes>]> = lhs.simd_lines().collect();
+                let data = rhs
+                    .simd_lines::<$lanes>()
+                    .flat_map(|rhs_col| {
+                        lhs_rows.iter().map(move |lhs_row| {
+                            lhs_row
+                                .iter()
+                                .zip(rhs_col.iter())
+                                .map(|(a, b)| a * b)
+                                .sum::<Simd<$type, $lanes>>()
+                                .reduce_sum()
+                        })
+                    })
+                    .collect();
+                Array {
+                    data,
+                    shape,
+                    data_order: DataOrder::ColMajor, // the result is filled column by column
+                }
+            }
+        }
+        impl Array<$type, 2> {
+            /// Multiplication with explicit SIMD, see `ArrayView::mul_simd`
+            pub fn mul_simd<V: AsArrayView<$type, 2>>(&self, rhs: &V) -> Array<$type, 2> {
+                self.view().mul_simd(rhs)
+            }
+        }
+    )+};
+}
+impl_mul_simd!(f32, 8; f64, 4);
+
+// Strassen's algorithm combines sub-blocks by subtraction
+impl<T: Ring> Array<T, 2> {
     /// Strassen algorithm of matrix multiplcation, complexity `O(n^{log_2 7})=O(n^{2.807})`
     pub fn mul_strassen(&self, rhs: &Array<T, 2>) -> Array<T, 2> {
-        Array::matrix_multiplication_check(self, rhs);
+        Array::matrix_multiplication_check(&self.view(), &rhs.view())
+            .unwrap_or_else(|error| panic!("{error}"));
         todo!()
     }
+}
 
+// decompositions need exact division and square roots
+impl<T: RealFloat> Array<T, 2> {
     /// SVD
     pub fn svd(&self) -> (Array<T, 2>, Array<T, 2>, Array<T, 2>) {
         todo!()
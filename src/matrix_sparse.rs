@@ -0,0 +1,381 @@
+use crate::array_basic::{Array, DataOrder};
+use crate::array_error::{check_bounds, check_same_shape, ShapeError};
+use crate::array_view::AsArrayView;
+use crate::scalar::{AdditiveIdentity, Arithmetic, Scalar};
+use std::ops::Add;
+
+// ### Sparse Matrix in Compressed Sparse Column Format
+/// Matrix of shape `[m, n]` storing only its nonzero entries, column by column, as julia's `SparseMatrixCSC`
+///
+/// The stored entries of column `j` are `nzval[colptr[j]..colptr[j + 1]]` in the rows `rowval[colptr[j]..colptr[j + 1]]`, which are zero-based and strictly increasing, so `colptr` has `n + 1` elements starting at `0`. Build one from triplets with `SparseBuilder`, or from a dense matrix with `from_dense`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseCSC<T: Scalar> {
+    pub shape: [usize; 2],
+    pub colptr: Vec<usize>,
+    pub rowval: Vec<usize>,
+    pub nzval: Vec<T>,
+}
+
+/// Builder of a `SparseCSC` from `(row, column, value)` triplets in any order, i.e., the coordinate (COO) format, as julia's `sparse(I, J, V, m, n)`
+///
+/// Triplets at the same position are summed by `build`. As in julia, stored values that are (or sum to) zero are kept, see `SparseCSC::dropzeros`.
+#[derive(Debug, Clone)]
+pub struct SparseBuilder<T: Scalar> {
+    pub shape: [usize; 2],
+    pub rows: Vec<usize>,
+    pub cols: Vec<usize>,
+    pub vals: Vec<T>,
+}
+
+impl<T: Scalar> SparseBuilder<T> {
+    /// Builder of a matrix of the given shape without any triplet
+    pub fn new(shape: [usize; 2]) -> Self {
+        SparseBuilder::with_capacity(shape, 0)
+    }
+    /// Builder with room for `capacity` triplets
+    pub fn with_capacity(shape: [usize; 2], capacity: usize) -> Self {
+        SparseBuilder {
+            shape,
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            vals: Vec::with_capacity(capacity),
+        }
+    }
+    /// Add `value` at `[i, j]`, on top of the values already pushed there
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        self.try_push(i, j, value)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `push`, returning `ShapeError::IndexOutOfBounds` if `[i, j]` lies outside the shape
+    pub fn try_push(&mut self, i: usize, j: usize, value: T) -> Result<(), ShapeError> {
+        check_bounds(&self.shape, &[i, j])?;
+        self.rows.push(i);
+        self.cols.push(j);
+        self.vals.push(value);
+        Ok(())
+    }
+}
+
+/// Push every `(row, column, value)` triplet, panicking on the first one outside the shape
+impl<T: Scalar> Extend<(usize, usize, T)> for SparseBuilder<T> {
+    fn extend<I: IntoIterator<Item = (usize, usize, T)>>(&mut self, triplets: I) {
+        for (i, j, value) in triplets {
+            self.push(i, j, value);
+        }
+    }
+}
+
+impl<T: Scalar + Arithmetic<T>> SparseBuilder<T> {
+    /// Compress the triplets into a `SparseCSC`, summing the values at the same position
+    ///
+    /// The triplets are bucketed by column with a counting sort, then sorted by row within each column, in `O(nnz log(nnz / n) + n)`.
+    pub fn build(self) -> SparseCSC<T> {
+        let [rows, cols] = self.shape;
+        // start of every column in `order`, which lists the triplets column by column
+        let mut starts = vec![0; cols + 1];
+        self.cols.iter().for_each(|&j| starts[j + 1] += 1);
+        for j in 0..cols {
+            starts[j + 1] += starts[j];
+        }
+        let mut next = starts.clone();
+        let mut order = vec![0; self.vals.len()];
+        for (k, &j) in self.cols.iter().enumerate() {
+            order[next[j]] = k;
+            next[j] += 1;
+        }
+
+        let mut res = SparseCSC::<T>::zeros([rows, cols]);
+        res.rowval.reserve(self.vals.len());
+        res.nzval.reserve(self.vals.len());
+        for j in 0..cols {
+            let column = &mut order[starts[j]..starts[j + 1]];
+            column.sort_by_key(|&k| self.rows[k]);
+            let start = res.rowval.len();
+            for &k in column.iter() {
+                let (i, value) = (self.rows[k], self.vals[k].clone());
+                match res.rowval.last() {
+                    Some(&last) if res.rowval.len() > start && last == i => {
+                        let sum = res.nzval.last_mut().unwrap();
+                        *sum = sum.clone() + value;
+                    }
+                    _ => {
+                        res.rowval.push(i);
+                        res.nzval.push(value);
+                    }
+                }
+            }
+            res.colptr[j + 1] = res.rowval.len();
+        }
+        res
+    }
+}
+
+/* Construction, conversion and element access */
+impl<T: Scalar> SparseCSC<T> {
+    /// Matrix of the given shape without any stored entry, i.e., all zeros, as julia's `spzeros(m, n)`
+    pub fn zeros(shape: [usize; 2]) -> Self {
+        SparseCSC {
+            shape,
+            colptr: vec![0; shape[1] + 1],
+            rowval: Vec::new(),
+            nzval: Vec::new(),
+        }
+    }
+    /// Matrix of the given shape from `(row, column, value)` triplets, summing duplicates, see `SparseBuilder`
+    pub fn from_triplets<I: IntoIterator<Item = (usize, usize, T)>>(
+        shape: [usize; 2],
+        triplets: I,
+    ) -> Self
+    where
+        T: Arithmetic<T>,
+    {
+        SparseCSC::try_from_triplets(shape, triplets).unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `from_triplets`, returning `ShapeError::IndexOutOfBounds` for the first triplet outside the shape
+    pub fn try_from_triplets<I: IntoIterator<Item = (usize, usize, T)>>(
+        shape: [usize; 2],
+        triplets: I,
+    ) -> Result<Self, ShapeError>
+    where
+        T: Arithmetic<T>,
+    {
+        let mut builder = SparseBuilder::new(shape);
+        for (i, j, value) in triplets {
+            builder.try_push(i, j, value)?;
+        }
+        Ok(builder.build())
+    }
+    /// Number of stored entries, as julia's `nnz`
+    pub fn nnz(&self) -> usize {
+        self.nzval.len()
+    }
+    /// Rows and values of the entries stored in column `j`
+    pub fn column(&self, j: usize) -> (&[usize], &[T]) {
+        let range = self.colptr[j]..self.colptr[j + 1];
+        (&self.rowval[range.clone()], &self.nzval[range])
+    }
+    /// Iterate the stored entries as `([i, j], &value)`, column by column, as julia's `findnz`
+    pub fn iter(&self) -> impl Iterator<Item = ([usize; 2], &T)> + '_ {
+        (0..self.shape[1]).flat_map(move |j| {
+            let (rows, values) = self.column(j);
+            rows.iter()
+                .zip(values.iter())
+                .map(move |(&i, value)| ([i, j], value))
+        })
+    }
+    /// Reference to the stored entry at `[i, j]`, or `None` if it is not stored or out of bounds
+    pub fn get(&self, [i, j]: [usize; 2]) -> Option<&T> {
+        if i >= self.shape[0] || j >= self.shape[1] {
+            return None;
+        }
+        let (rows, values) = self.column(j);
+        rows.binary_search(&i).ok().map(|k| &values[k])
+    }
+    /// Transpose, by regrouping the entries row by row in `O(nnz + m + n)`
+    pub fn transpose(&self) -> SparseCSC<T> {
+        let [rows, cols] = self.shape;
+        let mut colptr = vec![0; rows + 1];
+        self.rowval.iter().for_each(|&i| colptr[i + 1] += 1);
+        for i in 0..rows {
+            colptr[i + 1] += colptr[i];
+        }
+        // visiting the columns in order keeps the rows of the transpose increasing
+        let mut next = colptr.clone();
+        let mut rowval = vec![0; self.nnz()];
+        let mut nzval = vec![None; self.nnz()];
+        for ([i, j], value) in self.iter() {
+            rowval[next[i]] = j;
+            nzval[next[i]] = Some(value.clone());
+            next[i] += 1;
+        }
+        SparseCSC {
+            shape: [cols, rows],
+            colptr,
+            rowval,
+            nzval: nzval.into_iter().map(Option::unwrap).collect(),
+        }
+    }
+}
+
+impl<T: AdditiveIdentity> SparseCSC<T> {
+    /// Store the nonzero elements of a dense matrix or view, whatever its layout, as julia's `sparse(A)`
+    pub fn from_dense<V: AsArrayView<T, 2>>(src: &V) -> Self {
+        let src = src.as_view();
+        let mut res = SparseCSC::zeros(src.shape);
+        for j in 0..src.shape[1] {
+            for i in 0..src.shape[0] {
+                let value = &src[[i, j]];
+                if !value.is_zero() {
+                    res.rowval.push(i);
+                    res.nzval.push(value.clone());
+                }
+            }
+            res.colptr[j + 1] = res.rowval.len();
+        }
+        res
+    }
+    /// Column-major dense copy, with zeros where no entry is stored, as julia's `Matrix(A)`
+    pub fn to_dense(&self) -> Array<T, 2> {
+        let mut res = Array::zeros(self.shape, DataOrder::ColMajor);
+        for ([i, j], value) in self.iter() {
+            res.data[i + j * self.shape[0]] = value.clone();
+        }
+        res
+    }
+    /// Remove the stored entries that are zero, as julia's `dropzeros!`
+    pub fn dropzeros(&mut self) {
+        let mut kept = 0;
+        for j in 0..self.shape[1] {
+            let range = self.colptr[j]..self.colptr[j + 1];
+            for k in range {
+                if !self.nzval[k].is_zero() {
+                    self.rowval.swap(kept, k);
+                    self.nzval.swap(kept, k);
+                    kept += 1;
+                }
+            }
+            self.colptr[j + 1] = kept;
+        }
+        self.rowval.truncate(kept);
+        self.nzval.truncate(kept);
+    }
+}
+
+/* Products and sums; the results of sparse-sparse operations store no zeros */
+impl<T: Scalar + Arithmetic<T>> SparseCSC<T> {
+    #[inline]
+    fn inner_dimension_check(&self, rhs_shape: &[usize]) -> Result<(), ShapeError> {
+        if self.shape[1] == rhs_shape[0] {
+            return Ok(());
+        }
+        Err(ShapeError::InnerDimensionMismatch {
+            lhs: self.shape.to_vec(),
+            rhs: rhs_shape.to_vec(),
+        })
+    }
+    /// Product with a dense vector, visiting every stored entry once
+    pub fn mul_vector<V: AsArrayView<T, 1>>(&self, rhs: &V) -> Array<T, 1> {
+        self.try_mul_vector(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `mul_vector`, returning `ShapeError::InnerDimensionMismatch` if the columns of `self` do not match the length of `rhs`
+    pub fn try_mul_vector<V: AsArrayView<T, 1>>(&self, rhs: &V) -> Result<Array<T, 1>, ShapeError> {
+        let rhs = rhs.as_view();
+        self.inner_dimension_check(&rhs.shape)?;
+        let mut res_data = vec![T::ZERO; self.shape[0]];
+        for ([i, j], value) in self.iter() {
+            res_data[i] = res_data[i].clone() + value.clone() * rhs[[j]].clone();
+        }
+        Ok(Array {
+            data: res_data,
+            shape: [self.shape[0]],
+            data_order: DataOrder::ColMajor,
+        })
+    }
+    /// Product with a dense matrix, column by column of `rhs`; the result is column-major
+    pub fn mul_dense<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Array<T, 2> {
+        self.try_mul_dense(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `mul_dense`, returning `ShapeError::InnerDimensionMismatch` if the columns of `self` do not match the rows of `rhs`
+    pub fn try_mul_dense<V: AsArrayView<T, 2>>(&self, rhs: &V) -> Result<Array<T, 2>, ShapeError> {
+        let rhs = rhs.as_view();
+        self.inner_dimension_check(&rhs.shape)?;
+        let (res_row, res_col) = (self.shape[0], rhs.shape[1]);
+        let mut res_data = vec![T::ZERO; res_row * res_col];
+        for (c, res_col_data) in res_data.chunks_exact_mut(res_row.max(1)).enumerate() {
+            for ([i, j], value) in self.iter() {
+                res_col_data[i] = res_col_data[i].clone() + value.clone() * rhs[[j, c]].clone();
+            }
+        }
+        Ok(Array {
+            data: res_data,
+            shape: [res_row, res_col],
+            data_order: DataOrder::ColMajor,
+        })
+    }
+    /// Sparse product, by accumulating every column of the result in a dense workspace (Gustavson's algorithm)
+    pub fn mul_sparse(&self, rhs: &SparseCSC<T>) -> SparseCSC<T> {
+        self.try_mul_sparse(rhs)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+    /// Fallible `mul_sparse`, returning `ShapeError::InnerDimensionMismatch` if the columns of `self` do not match the rows of `rhs`
+    pub fn try_mul_sparse(&self, rhs: &SparseCSC<T>) -> Result<SparseCSC<T>, ShapeError> {
+        self.inner_dimension_check(&rhs.shape)?;
+        let mut res = SparseCSC::zeros([self.shape[0], rhs.shape[1]]);
+        let mut workspace = vec![T::ZERO; self.shape[0]];
+        let mut touched = vec![false; self.shape[0]];
+        let mut rows = Vec::new();
+        for j in 0..rhs.shape[1] {
+            let (inner_rows, rhs_values) = rhs.column(j);
+            for (&k, rhs_value) in inner_rows.iter().zip(rhs_values.iter()) {
+                let (lhs_rows, lhs_values) = self.column(k);
+                for (&i, lhs_value) in lhs_rows.iter().zip(lhs_values.iter()) {
+                    if !touched[i] {
+                        touched[i] = true;
+                        rows.push(i);
+                    }
+                    workspace[i] = workspace[i].clone() + lhs_value.clone() * rhs_value.clone();
+                }
+            }
+            rows.sort_unstable();
+            for &i in rows.iter() {
+                touched[i] = false;
+                let value = std::mem::replace(&mut workspace[i], T::ZERO);
+                if !value.is_zero() {
+                    res.rowval.push(i);
+                    res.nzval.push(value);
+                }
+            }
+            rows.clear();
+            res.colptr[j + 1] = res.rowval.len();
+        }
+        Ok(res)
+    }
+    /// Fallible `+`, returning `ShapeError::ShapeMismatch` if the shapes differ
+    pub fn try_add(&self, rhs: &SparseCSC<T>) -> Result<SparseCSC<T>, ShapeError> {
+        check_same_shape(&self.shape, &rhs.shape)?;
+        let mut res = SparseCSC::zeros(self.shape);
+        for j in 0..self.shape[1] {
+            let (lhs_rows, lhs_values) = self.column(j);
+            let (rhs_rows, rhs_values) = rhs.column(j);
+            // merge the two sorted columns
+            let (mut a, mut b) = (0, 0);
+            while a < lhs_rows.len() || b < rhs_rows.len() {
+                let (i, value) = match (lhs_rows.get(a), rhs_rows.get(b)) {
+                    (Some(&i), Some(&k)) if i == k => {
+                        (a, b) = (a + 1, b + 1);
+                        (i, lhs_values[a - 1].clone() + rhs_values[b - 1].clone())
+                    }
+                    (Some(&i), Some(&k)) if i < k => {
+                        a += 1;
+                        (i, lhs_values[a - 1].clone())
+                    }
+                    (Some(&i), None) => {
+                        a += 1;
+                        (i, lhs_values[a - 1].clone())
+                    }
+                    (_, Some(&k)) => {
+                        b += 1;
+                        (k, rhs_values[b - 1].clone())
+                    }
+                    (None, None) => unreachable!(),
+                };
+                if !value.is_zero() {
+                    res.rowval.push(i);
+                    res.nzval.push(value);
+                }
+            }
+            res.colptr[j + 1] = res.rowval.len();
+        }
+        Ok(res)
+    }
+}
+
+/// Element-wise sum of two sparse matrices of the same shape
+impl<T: Scalar + Arithmetic<T>> Add for &SparseCSC<T> {
+    type Output = SparseCSC<T>;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.try_add(rhs).unwrap_or_else(|error| panic!("{error}"))
+    }
+}
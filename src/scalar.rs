@@ -1,29 +1,131 @@
-// use num_traits::*;
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
 use std::fmt::Display;
-use std::ops::{Add, Div, Mul, Sub};
+use std::ops::{Div, Sub};
 
 /// **Minimal** trait bound for the datatype to be stored in `Array<T>`
 ///
 /// Note: `Clone` is OK here since *No Extra Overhead* will occurs for numerical types that satisfy `Copy` trait
-pub trait Scalar: Clone + Display + Sized {}
+pub trait Scalar: Clone + Display + Sized {
+    /// Text of the element in the display of arrays, with `decimal_length` digits after the point where that applies
+    fn display_string(&self, decimal_length: usize) -> String {
+        format!("{self:.decimal_length$}")
+    }
+}
 // impl the trait bound `Scalar` for all built-in numeric types
 macro_rules! impl_Scalar_for_types {
     ($($type:ty) *) => ($( // `ty` is specific for the fragment specifier `type`
         impl Scalar for $type {}
     )*)
 }
-impl_Scalar_for_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+impl_Scalar_for_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64 bool);
+// complex numbers are displayed as in julia, e.g., `1.0 + 2.0im`
+macro_rules! impl_Scalar_for_complex_types {
+    ($($type:ty) *) => ($(
+        impl Scalar for Complex<$type> {
+            fn display_string(&self, decimal_length: usize) -> String {
+                let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+                format!(
+                    "{:.decimal_length$} {sign} {:.decimal_length$}im",
+                    self.re,
+                    self.im.abs()
+                )
+            }
+        }
+    )*)
+}
+impl_Scalar_for_complex_types!(f32 f64);
 
-/// **Minimal** trait bound to support basic linear-algebra operations for `Array<T>`
-pub trait Arithmetic<T>: Add<Output = T> + Mul<Output = T> + Sized {
+/* Layered numeric traits: each level states exactly the operations an algorithm may rely on */
+/// Scalars with a neutral element `ZERO` of addition, usable in `const` context (e.g., `zeros!`)
+pub trait AdditiveIdentity: Scalar + Zero {
     const ZERO: Self;
 }
-// impl the trait bound `Arithmetic` for all built-in numeric types
-macro_rules! impl_Arithmetic_for_types {
+/// Scalars with a neutral element `ONE` of multiplication, usable in `const` context (e.g., `ones!`)
+pub trait MultiplicativeIdentity: Scalar + One {
+    const ONE: Self;
+}
+
+/// **Minimal** trait bound to support basic linear-algebra operations for `Array<T>`, i.e., `+`, `*`, `ZERO` and `ONE`
+///
+/// It is implemented for every type with both identities (which bring `Add` and `Mul` through `num_traits::Zero` and `num_traits::One`), so that it is never implemented by hand; the parameter `T` is the type itself.
+pub trait Arithmetic<T = Self>: AdditiveIdentity + MultiplicativeIdentity {}
+impl<T: AdditiveIdentity + MultiplicativeIdentity> Arithmetic<T> for T {}
+
+/// Scalars closed under `+`, `-` and `*`, e.g., the built-in signed integers and floats
+///
+/// Unsigned integers are **not** rings, since a difference may fall below zero; they are `Arithmetic` only.
+pub trait Ring: Arithmetic<Self> + Sub<Output = Self> {}
+/// Rings with exact division `/`, e.g., floats but **not** integers, whose division truncates
+pub trait Field: Ring + Div<Output = Self> {}
+/// Ordered floating-point fields with `sqrt`, `abs`, `NAN`, ..., i.e., `f32` and `f64`
+pub trait RealFloat: Field + PartialOrd + Float {}
+
+// impl the identities for all built-in numeric types
+macro_rules! impl_identities_for_types {
     ($($type:ty) *) => ($( // `ty` is specific for the fragment specifier `type`
-        impl Arithmetic<$type> for $type {
+        impl AdditiveIdentity for $type {
             const ZERO: Self = 0 as $type; // coerce type conversion
         }
+        impl MultiplicativeIdentity for $type {
+            const ONE: Self = 1 as $type;
+        }
+    )*)
+}
+impl_identities_for_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+// impl `Ring` for the built-in signed numeric types
+macro_rules! impl_Ring_for_types {
+    ($($type:ty) *) => ($(
+        impl Ring for $type {}
+    )*)
+}
+impl_Ring_for_types!(isize i8 i16 i32 i64 f32 f64);
+// impl the identities, `Ring` and `Field` for the complex floating-point types
+macro_rules! impl_Field_for_complex_types {
+    ($($type:ty) *) => ($(
+        impl AdditiveIdentity for Complex<$type> {
+            const ZERO: Self = Complex::new(0.0, 0.0);
+        }
+        impl MultiplicativeIdentity for Complex<$type> {
+            const ONE: Self = Complex::new(1.0, 0.0);
+        }
+        impl Ring for Complex<$type> {}
+        impl Field for Complex<$type> {}
+    )*)
+}
+impl_Field_for_complex_types!(f32 f64);
+// impl `Field` and `RealFloat` for the built-in floating-point types
+macro_rules! impl_RealFloat_for_types {
+    ($($type:ty) *) => ($(
+        impl Field for $type {}
+        impl RealFloat for $type {}
+    )*)
+}
+impl_RealFloat_for_types!(f32 f64);
+
+/// Scalars with a complex conjugate, which is the identity for real types
+///
+/// Called as `Conjugate::conj(&x)`, since `num_complex::ComplexFloat` has a method of the same name.
+pub trait Conjugate: Scalar {
+    fn conj(&self) -> Self;
+}
+macro_rules! impl_Conjugate_for_types {
+    ($($type:ty) *) => ($(
+        impl Conjugate for $type {
+            fn conj(&self) -> Self {
+                *self
+            }
+        }
+    )*)
+}
+impl_Conjugate_for_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+macro_rules! impl_Conjugate_for_complex_types {
+    ($($type:ty) *) => ($(
+        impl Conjugate for Complex<$type> {
+            fn conj(&self) -> Self {
+                Complex::conj(self)
+            }
+        }
     )*)
 }
-impl_Arithmetic_for_types!(usize isize u8 u16 u32 u64 i8 i16 i32 i64 f32 f64);
+impl_Conjugate_for_complex_types!(f32 f64);